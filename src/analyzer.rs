@@ -0,0 +1,531 @@
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{Error, Result};
+use std::sync::OnceLock;
+
+use crate::analyzers;
+use crate::config::Config;
+use crate::finding::{Finding, Rule, Severity};
+use crate::graph::Graph;
+use crate::index::ProjectIndex;
+use crate::table::Table;
+use crate::workspace::Project;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Status {
+    Ok,
+    Warning,
+    Error,
+    Skipped,
+}
+
+impl Status {
+    // 指摘の最大重要度から求める (Info は影響しない)
+    pub fn from_findings(findings: &[Finding]) -> Self {
+        match findings.iter().map(|f| f.severity).max() {
+            Some(Severity::Error) => Status::Error,
+            Some(Severity::Warning) => Status::Warning,
+            _ => Status::Ok,
+        }
+    }
+
+    // プロジェクト全体の判定。スキップされたカテゴリは無視する
+    pub fn overall(results: &[AnalysisResult]) -> Self {
+        let statuses: Vec<Status> = results
+            .iter()
+            .map(|r| r.status)
+            .filter(|s| *s != Status::Skipped)
+            .collect();
+        if statuses.is_empty() && !results.is_empty() {
+            Status::Skipped
+        } else if statuses.contains(&Status::Error) {
+            Status::Error
+        } else if statuses.contains(&Status::Warning) {
+            Status::Warning
+        } else {
+            Status::Ok
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Status::Ok => "OK",
+            Status::Warning => "WARNING",
+            Status::Error => "ERROR",
+            Status::Skipped => "SKIPPED",
+        };
+        f.write_str(label)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalysisResult {
+    // 解析器 ID (analyze_all が設定する)
+    #[serde(default)]
+    pub id: String,
+    pub category: String,
+    pub status: Status,
+    pub details: Vec<String>,
+    pub findings: Vec<Finding>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tables: Vec<Table>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub graphs: Vec<Graph>,
+}
+
+impl AnalysisResult {
+    pub fn new(category: &str) -> Self {
+        Self {
+            id: String::new(),
+            category: category.to_string(),
+            status: Status::Ok,
+            details: Vec::new(),
+            findings: Vec::new(),
+            tables: Vec::new(),
+            graphs: Vec::new(),
+        }
+    }
+
+    // 解析対象が無いなどの理由で実施しなかったカテゴリ
+    pub fn skipped(category: &str, reason: &str) -> Self {
+        let mut result = Self::new(category);
+        result.status = Status::Skipped;
+        result.details.push(reason.to_string());
+        result
+    }
+
+    // 指摘内容からステータスを更新する (Skipped はそのまま)
+    pub fn update_status(&mut self) {
+        if self.status != Status::Skipped {
+            self.status = Status::from_findings(&self.findings);
+        }
+    }
+
+    pub fn push(&mut self, finding: Finding) {
+        self.findings.push(finding);
+    }
+
+    // ファイル内の位置を付けて追加する (ファイルを読めなければ位置なしで追加)
+    pub fn push_at(
+        &mut self,
+        index: &ProjectIndex,
+        path: &str,
+        start: usize,
+        end: usize,
+        finding: Finding,
+    ) {
+        match index.get(path).and_then(|f| f.content()) {
+            Some(content) => self.push(finding.at(path, content, start, end)),
+            None => self.push(finding),
+        }
+    }
+
+    pub fn findings_with(&self, severity: Severity) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(move |f| f.severity == severity)
+    }
+}
+
+// ワークスペース全体の結果と、プロジェクトごとの結果
+pub type ProjectResults = (Vec<AnalysisResult>, Vec<(Project, Vec<AnalysisResult>)>);
+
+// 全解析器が共有するプロジェクト情報
+#[derive(Debug)]
+pub struct ProjectContext {
+    pub project_path: String,
+    pub index: ProjectIndex,
+    pub config: Config,
+}
+
+impl ProjectContext {
+    pub fn new(project_path: String) -> Result<Self> {
+        Self::with_config(project_path, Config::default())
+    }
+
+    pub fn with_config(project_path: String, config: Config) -> Result<Self> {
+        let index = ProjectIndex::build(&project_path)?;
+        Ok(Self {
+            project_path,
+            index,
+            config,
+        })
+    }
+}
+
+// 解析カテゴリの共通インターフェース
+pub trait Analyzer: Send + Sync {
+    // 有効/無効の切り替えに使う識別子 (例: "security")
+    fn id(&self) -> &str;
+
+    // レポートに表示するカテゴリ名
+    fn category(&self) -> &str;
+
+    // この解析器が報告しうるルール
+    fn rules(&self) -> &[Rule] {
+        &[]
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult>;
+}
+
+// 登録順に実行される解析器の一覧
+#[derive(Default)]
+pub struct AnalyzerRegistry {
+    analyzers: Vec<Box<dyn Analyzer>>,
+    disabled: HashSet<String>,
+}
+
+impl AnalyzerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 標準の11カテゴリを登録したレジストリ
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        analyzers::register_defaults(&mut registry);
+        registry
+    }
+
+    pub fn register<A: Analyzer + 'static>(&mut self, analyzer: A) -> &mut Self {
+        self.analyzers.push(Box::new(analyzer));
+        self
+    }
+
+    pub fn disable(&mut self, id: &str) -> &mut Self {
+        self.disabled.insert(id.to_string());
+        self
+    }
+
+    pub fn enable(&mut self, id: &str) -> &mut Self {
+        self.disabled.remove(id);
+        self
+    }
+
+    pub fn is_enabled(&self, id: &str) -> bool {
+        !self.disabled.contains(id)
+    }
+
+    pub fn ids(&self) -> Vec<&str> {
+        self.analyzers.iter().map(|a| a.id()).collect()
+    }
+
+    // 有効な解析器が定義するルールの一覧 (登録順)
+    pub fn rules(&self) -> Vec<Rule> {
+        self.enabled()
+            .flat_map(|a| a.rules().iter().copied())
+            .collect()
+    }
+
+    pub fn enabled(&self) -> impl Iterator<Item = &dyn Analyzer> {
+        self.analyzers
+            .iter()
+            .map(|a| a.as_ref())
+            .filter(|a| self.is_enabled(a.id()))
+    }
+}
+
+pub struct FrontendAnalyzer {
+    pub project_path: String,
+    pub registry: AnalyzerRegistry,
+    // 並列実行のスレッド数 (0 の場合は CPU コア数)
+    pub jobs: usize,
+    pub config: Config,
+    // jobs から初回の解析時に作り、以降の解析 (プロジェクトごとの解析を含む) で使い回す
+    pool: OnceLock<ThreadPool>,
+}
+
+impl FrontendAnalyzer {
+    pub fn new(project_path: String) -> Self {
+        Self::with_registry(project_path, AnalyzerRegistry::with_defaults())
+    }
+
+    pub fn with_registry(project_path: String, registry: AnalyzerRegistry) -> Self {
+        Self {
+            project_path,
+            registry,
+            jobs: 0,
+            config: Config::default(),
+            pool: OnceLock::new(),
+        }
+    }
+
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs;
+        self.pool = OnceLock::new();
+        self
+    }
+
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    // 有効な解析器のルールに設定ファイルの重要度を反映したもの (無効にしたルールは除く)
+    pub fn rules(&self) -> Vec<Rule> {
+        self.registry
+            .rules()
+            .into_iter()
+            .filter_map(|rule| {
+                let severity = self.config.severity(rule.id, rule.severity)?;
+                Some(Rule { severity, ..rule })
+            })
+            .collect()
+    }
+
+    // 結果は並列実行時も登録順に並ぶ
+    pub fn analyze_all(&self) -> Result<Vec<AnalysisResult>> {
+        let ctx = ProjectContext::with_config(self.project_path.clone(), self.config.clone())?;
+        self.analyze_context(&ctx)
+    }
+
+    // analyze_all の結果に加えて、ワークスペースの各プロジェクトを、そのディレクトリ (sourceRoot) を
+    // ルートとして個別に解析する (ワークスペースでなければプロジェクトごとの結果は空)
+    // プロジェクトのインデックスは全体のインデックスから切り出すため、ファイルは一度だけ読み込む
+    pub fn analyze_projects(&self) -> Result<ProjectResults> {
+        let ctx = ProjectContext::with_config(self.project_path.clone(), self.config.clone())?;
+        let results = self.analyze_context(&ctx)?;
+        let Some(workspace) = ctx.index.workspace() else {
+            return Ok((results, Vec::new()));
+        };
+        let projects = workspace
+            .projects
+            .iter()
+            .map(|project| {
+                let root = project.analysis_root();
+                let project_ctx = ProjectContext {
+                    project_path: root.to_string(),
+                    index: ctx.index.subset(root),
+                    config: self.config.for_project(ctx.index.relative(root)),
+                };
+                Ok((project.clone(), self.analyze_context(&project_ctx)?))
+            })
+            .collect::<Result<_>>()?;
+        Ok((results, projects))
+    }
+
+    fn pool(&self) -> Result<&ThreadPool> {
+        if let Some(pool) = self.pool.get() {
+            return Ok(pool);
+        }
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(self.jobs)
+            .build()
+            .map_err(Error::other)?;
+        Ok(self.pool.get_or_init(|| pool))
+    }
+
+    fn analyze_context(&self, ctx: &ProjectContext) -> Result<Vec<AnalysisResult>> {
+        self.pool()?.install(|| {
+            ctx.index.preload();
+
+            let analyzers: Vec<&dyn Analyzer> = self.registry.enabled().collect();
+            analyzers
+                .par_iter()
+                .map(|analyzer| {
+                    let mut result = analyzer.analyze(ctx)?;
+                    result.id = analyzer.id().to_string();
+                    result.findings.retain_mut(|finding| {
+                        match ctx.config.severity(&finding.rule_id, finding.severity) {
+                            Some(severity) => {
+                                finding.severity = severity;
+                                true
+                            }
+                            None => false,
+                        }
+                    });
+                    result.update_status();
+                    Ok(result)
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleLevel;
+    use crate::testing::TempProject;
+
+    #[test]
+    fn status_from_findings_and_overall() {
+        let finding = |severity| Finding::new("x", severity, "m");
+        assert_eq!(Status::from_findings(&[]), Status::Ok);
+        // Info だけなら OK
+        assert_eq!(
+            Status::from_findings(&[finding(Severity::Info)]),
+            Status::Ok
+        );
+        assert_eq!(
+            Status::from_findings(&[finding(Severity::Warning), finding(Severity::Info)]),
+            Status::Warning
+        );
+        assert_eq!(
+            Status::from_findings(&[finding(Severity::Warning), finding(Severity::Error)]),
+            Status::Error
+        );
+
+        let result = |status| {
+            let mut result = AnalysisResult::new("c");
+            result.status = status;
+            result
+        };
+        assert_eq!(Status::overall(&[]), Status::Ok);
+        assert_eq!(
+            Status::overall(&[result(Status::Skipped), result(Status::Skipped)]),
+            Status::Skipped
+        );
+        assert_eq!(
+            Status::overall(&[result(Status::Skipped), result(Status::Ok)]),
+            Status::Ok
+        );
+        assert_eq!(
+            Status::overall(&[
+                result(Status::Error),
+                result(Status::Warning),
+                result(Status::Skipped)
+            ]),
+            Status::Error
+        );
+
+        // スキップしたカテゴリは指摘があっても SKIPPED のまま
+        let mut skipped = AnalysisResult::skipped("c", "対象なし");
+        skipped.push(finding(Severity::Error));
+        skipped.update_status();
+        assert_eq!(skipped.status, Status::Skipped);
+        assert_eq!(Status::Warning.to_string(), "WARNING");
+    }
+
+    #[test]
+    fn thread_pool_is_built_once() {
+        let project = TempProject::new(&[("src/main.ts", "bootstrapApplication(App);\n")]);
+        let analyzer = FrontendAnalyzer::new(project.root.clone()).with_jobs(2);
+        analyzer.analyze_all().unwrap();
+        let pool = analyzer.pool().unwrap() as *const ThreadPool;
+        analyzer.analyze_all().unwrap();
+        assert!(std::ptr::eq(pool, analyzer.pool().unwrap()));
+        assert_eq!(analyzer.pool().unwrap().current_num_threads(), 2);
+    }
+
+    #[test]
+    fn analyze_projects_runs_workspace_categories_per_project() {
+        let project = TempProject::new(&[
+            ("nx.json", "{}"),
+            (
+                "apps/shop/project.json",
+                r#"{ "name": "shop", "projectType": "application", "sourceRoot": "apps/shop/src",
+                     "targets": { "build": { "executor": "@angular-devkit/build-angular:browser" } } }"#,
+            ),
+            (
+                "apps/shop/src/main.ts",
+                "import { admin } from '../../admin/src/main';\n",
+            ),
+            (
+                "apps/admin/project.json",
+                r#"{ "name": "admin", "projectType": "application", "sourceRoot": "apps/admin/src" }"#,
+            ),
+            ("apps/admin/src/main.ts", "export const admin = 1;\n"),
+        ]);
+        let (_, projects) = FrontendAnalyzer::new(project.root.clone())
+            .analyze_projects()
+            .unwrap();
+        let names: Vec<&str> = projects.iter().map(|(p, _)| p.name.as_str()).collect();
+        assert_eq!(names, ["admin", "shop"]);
+
+        let category = |name: &str, id: &str| {
+            let (_, results) = projects.iter().find(|(p, _)| p.name == name).unwrap();
+            results.iter().find(|r| r.id == id).unwrap()
+        };
+        // プロジェクトのディレクトリの外にある nx.json を引き継ぎ、外のプロジェクトへの import も検査する
+        let shop = category("shop", "nx");
+        assert_eq!(shop.status, Status::Error);
+        assert_eq!(shop.findings[0].rule_id, "nx/enforce-module-boundaries");
+        assert_eq!(shop.tables[0].rows.len(), 1);
+        assert_eq!(category("admin", "nx").status, Status::Ok);
+        assert_ne!(category("shop", "build").status, Status::Skipped);
+    }
+
+    const TODO: Rule = Rule {
+        id: "todo/comment",
+        severity: Severity::Warning,
+        description: "TODO コメント",
+    };
+
+    // 1 件の指摘を返すだけの解析器
+    struct TodoAnalyzer;
+
+    impl Analyzer for TodoAnalyzer {
+        fn id(&self) -> &str {
+            "todo"
+        }
+
+        fn category(&self) -> &str {
+            "TODO"
+        }
+
+        fn rules(&self) -> &[Rule] {
+            &[TODO]
+        }
+
+        fn analyze(&self, _ctx: &ProjectContext) -> Result<AnalysisResult> {
+            let mut result = AnalysisResult::new("TODO");
+            result.push(TODO.finding("TODO が残っています"));
+            Ok(result)
+        }
+    }
+
+    #[test]
+    fn registry_runs_enabled_analyzers_in_order() {
+        let mut registry = AnalyzerRegistry::with_defaults();
+        let defaults = registry.ids().len();
+        registry
+            .register(TodoAnalyzer)
+            .disable("ui_screens")
+            .disable("security")
+            .enable("security");
+        assert_eq!(registry.ids().len(), defaults + 1);
+        assert!(!registry.is_enabled("ui_screens") && registry.is_enabled("security"));
+        assert_eq!(registry.rules().last().map(|r| r.id), Some(TODO.id));
+
+        let project = TempProject::new(&[("src/main.ts", "bootstrapApplication(App);\n")]);
+        let results = FrontendAnalyzer::with_registry(project.root.clone(), registry)
+            .analyze_all()
+            .unwrap();
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids.len(), defaults);
+        assert_eq!(ids[0], "data_storage");
+        assert_eq!(ids.last(), Some(&"todo"));
+        assert_eq!(results.last().unwrap().status, Status::Warning);
+    }
+
+    #[test]
+    fn config_overrides_rule_severity() {
+        let project = TempProject::new(&[("src/main.ts", "")]);
+        let analyze = |level| {
+            let mut registry = AnalyzerRegistry::new();
+            registry.register(TodoAnalyzer);
+            let mut config = Config::default();
+            config.rules.insert(TODO.id.to_string(), level);
+            let analyzer =
+                FrontendAnalyzer::with_registry(project.root.clone(), registry).with_config(config);
+            (analyzer.rules(), analyzer.analyze_all().unwrap())
+        };
+
+        let (rules, results) = analyze(RuleLevel::Error);
+        assert_eq!(rules[0].severity, Severity::Error);
+        assert_eq!(results[0].findings[0].severity, Severity::Error);
+        assert_eq!(results[0].status, Status::Error);
+
+        // 無効にしたルールは定義にも結果にも残さない
+        let (rules, results) = analyze(RuleLevel::Off);
+        assert!(rules.is_empty());
+        assert!(results[0].findings.is_empty());
+        assert_eq!(results[0].status, Status::Ok);
+    }
+}
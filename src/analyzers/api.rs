@@ -0,0 +1,110 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::index::FileKind;
+
+const CATEGORY: &str = "API";
+
+const NO_HTTP_CLIENT: Rule = Rule {
+    id: "api/no-http-client",
+    severity: Severity::Warning,
+    description: "HttpClient による HTTP 通信が見つからない",
+};
+
+const NO_ERROR_HANDLING: Rule = Rule {
+    id: "api/no-error-handling",
+    severity: Severity::Warning,
+    description: "API 呼び出しのエラーハンドリング (catchError 等) が見つからない",
+};
+
+const RULES: &[Rule] = &[NO_HTTP_CLIENT, NO_ERROR_HANDLING];
+
+// API解析
+pub struct ApiAnalyzer;
+
+impl Analyzer for ApiAnalyzer {
+    fn id(&self) -> &str {
+        "api"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        let mut http_client_usage = 0;
+        let mut api_endpoints = Vec::new();
+        let mut error_handling_count = 0;
+
+        let http_methods = ["get", "post", "put", "delete", "patch"];
+
+        for file in ctx.index.files(FileKind::Ts) {
+            if let Some(module) = file.module() {
+                // HTTP Clientの使用をチェック
+                if module.has_name("HttpClient")
+                    || module.members.iter().any(|m| m.object.ends_with("http"))
+                {
+                    http_client_usage += 1;
+                }
+
+                // APIエンドポイントを抽出
+                for literal in &module.strings {
+                    if literal.value.starts_with("http://") || literal.value.starts_with("https://")
+                    {
+                        api_endpoints.push(literal.value.clone());
+                    }
+                }
+
+                // HTTPメソッドの使用をチェック (レシーバーが http クライアントのもの)
+                for method in &http_methods {
+                    if module.method_calls(method).any(|call| {
+                        call.receiver()
+                            .is_some_and(|r| r.to_ascii_lowercase().contains("http"))
+                    }) {
+                        result
+                            .details
+                            .push(format!("HTTP {}メソッド使用確認", method.to_uppercase()));
+                    }
+                }
+
+                // エラーハンドリングをチェック
+                if module.calls_to("catchError").next().is_some()
+                    || module.method_calls("catch").next().is_some()
+                    || !module.try_blocks.is_empty()
+                {
+                    error_handling_count += 1;
+                }
+            }
+        }
+
+        result.details.push(format!(
+            "HTTPクライアント使用ファイル数: {}",
+            http_client_usage
+        ));
+        result.details.push(format!(
+            "検出されたAPIエンドポイント数: {}",
+            api_endpoints.len()
+        ));
+        result.details.push(format!(
+            "エラーハンドリング実装箇所: {}",
+            error_handling_count
+        ));
+
+        if http_client_usage == 0 {
+            result.push(NO_HTTP_CLIENT.finding("HTTP通信の実装が確認できません"));
+        }
+
+        if error_handling_count == 0 {
+            result.push(NO_ERROR_HANDLING.finding("APIエラーハンドリングが確認できません"));
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::graph::Graph;
+
+const CATEGORY: &str = "アーキテクチャ (レイヤー)";
+
+const FORBIDDEN_LAYER_IMPORT: Rule = Rule {
+    id: "architecture/forbidden-layer-import",
+    severity: Severity::Error,
+    description: "設定ファイルで許可されていないレイヤー間の import",
+};
+
+const RULES: &[Rule] = &[FORBIDDEN_LAYER_IMPORT];
+
+// 設定ファイルのレイヤー定義に基づく依存方向の検査
+pub struct ArchitectureAnalyzer;
+
+impl Analyzer for ArchitectureAnalyzer {
+    fn id(&self) -> &str {
+        "architecture"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let layers = &ctx.config.layers;
+        if layers.is_empty() {
+            return Ok(AnalysisResult::skipped(
+                CATEGORY,
+                "設定ファイルにレイヤー (layers) が定義されていません",
+            ));
+        }
+
+        let mut result = AnalysisResult::new(CATEGORY);
+        let imports = ctx.index.imports();
+
+        // 複数のレイヤーに一致するファイルは先に定義したレイヤーに属する
+        let layer_of: Vec<Option<usize>> = imports
+            .files
+            .iter()
+            .map(|path| {
+                let relative = ctx.index.relative(path);
+                layers.iter().position(|layer| layer.matches(relative))
+            })
+            .collect();
+
+        for (i, layer) in layers.iter().enumerate() {
+            let count = layer_of.iter().filter(|l| **l == Some(i)).count();
+            result
+                .details
+                .push(format!("レイヤー {}: {} ファイル", layer.name, count));
+        }
+        result.details.push(format!(
+            "どのレイヤーにも属さないファイル数: {}",
+            layer_of.iter().filter(|l| l.is_none()).count()
+        ));
+
+        // (import 元レイヤー, import 先レイヤー) ごとの import 数
+        let mut dependencies: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+        for (from, edges) in imports.edges.iter().enumerate() {
+            let Some(from_layer) = layer_of[from] else {
+                continue;
+            };
+            for edge in edges {
+                let Some(to_layer) = layer_of[edge.to] else {
+                    continue;
+                };
+                if from_layer == to_layer {
+                    continue;
+                }
+                *dependencies.entry((from_layer, to_layer)).or_default() += 1;
+                if layers[from_layer].allows(&layers[to_layer]) {
+                    continue;
+                }
+
+                let path = &imports.files[from];
+                let finding = FORBIDDEN_LAYER_IMPORT.finding(format!(
+                    "レイヤー {} から {} への import は許可されていません: {} → {}",
+                    layers[from_layer].name,
+                    layers[to_layer].name,
+                    ctx.index.relative(path),
+                    ctx.index.relative(&imports.files[edge.to])
+                ));
+                result.push_at(&ctx.index, path, edge.range.start, edge.range.end, finding);
+            }
+        }
+
+        let forbidden = dependencies
+            .keys()
+            .filter(|(from, to)| !layers[*from].allows(&layers[*to]))
+            .count();
+        result.details.push(format!(
+            "レイヤー間の依存: {} 種類 (うち許可されていないもの {} 種類)",
+            dependencies.len(),
+            forbidden
+        ));
+
+        // レイヤーをノード、import 数を辺のラベルにしたグラフ
+        let mut graph = Graph::new("layers", "レイヤー間の依存関係");
+        for layer in layers {
+            graph.add_node(&layer.name, &layer.name, "layer");
+        }
+        for (&(from, to), count) in &dependencies {
+            let label = if layers[from].allows(&layers[to]) {
+                format!("{} 件", count)
+            } else {
+                format!("禁止 {} 件", count)
+            };
+            graph.add_edge(&layers[from].name, &layers[to].name, &label);
+        }
+        result.graphs.push(graph);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Status;
+    use crate::config::{Config, DEFAULT_FILE};
+    use crate::testing::TempProject;
+
+    const CONFIG: &str = r#"{
+  // 先に定義したレイヤーが優先される
+  "layers": [
+    { "name": "core", "paths": ["src/app/core/**"] },
+    { "name": "shared", "paths": ["src/app/shared/**"], "allow": ["core"] },
+    { "name": "features", "paths": ["src/app/features/*/**"], "allow": ["core", "shared"] }
+  ]
+}"#;
+
+    fn analyze(files: &[(&str, &str)]) -> AnalysisResult {
+        let mut files = files.to_vec();
+        files.push((DEFAULT_FILE, CONFIG));
+        let project = TempProject::new(&files);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Config::discover(&project.root).unwrap(),
+        };
+        ArchitectureAnalyzer.analyze(&ctx).unwrap()
+    }
+
+    #[test]
+    fn forbidden_layer_imports() {
+        let result = analyze(&[
+            (
+                "src/app/core/auth.ts",
+                "import { Button } from '../shared/button';\nexport const auth = 1;\n",
+            ),
+            (
+                "src/app/shared/button.ts",
+                "import { auth } from '../core/auth';\nexport const Button = 1;\n",
+            ),
+            (
+                "src/app/features/cart/cart.ts",
+                "import { auth } from '../../core/auth';\nimport { Button } from '../../shared/button';\nimport { order } from '../order/order';\n",
+            ),
+            (
+                "src/app/features/order/order.ts",
+                "export const order = 1;\n",
+            ),
+            ("src/main.ts", "import { auth } from './app/core/auth';\n"),
+        ]);
+        let messages: Vec<(&str, usize)> = result
+            .findings
+            .iter()
+            .map(|f| {
+                (
+                    f.message.as_str(),
+                    f.location.as_ref().unwrap().span.start_line,
+                )
+            })
+            .collect();
+        // 同じレイヤー内 (features 同士) とレイヤーに属さないファイルからの import は対象外
+        assert_eq!(
+            messages,
+            [(
+                "レイヤー core から shared への import は許可されていません: src/app/core/auth.ts → src/app/shared/button.ts",
+                1
+            )]
+        );
+        assert!(
+            result
+                .details
+                .contains(&"どのレイヤーにも属さないファイル数: 1".to_string())
+        );
+        assert!(
+            result.details.contains(
+                &"レイヤー間の依存: 4 種類 (うち許可されていないもの 1 種類)".to_string()
+            )
+        );
+        let labels: Vec<&str> = result.graphs[0]
+            .edges_from("core")
+            .map(|e| e.label.as_str())
+            .collect();
+        assert_eq!(labels, ["禁止 1 件"]);
+    }
+
+    #[test]
+    fn skipped_without_layers() {
+        let project = TempProject::new(&[("src/main.ts", "")]);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Default::default(),
+        };
+        let result = ArchitectureAnalyzer.analyze(&ctx).unwrap();
+        assert_eq!(result.status, Status::Skipped);
+    }
+}
@@ -0,0 +1,123 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::index::FileKind;
+
+const CATEGORY: &str = "ログイン";
+
+const NO_AUTH_SERVICE: Rule = Rule {
+    id: "authentication/no-auth-service",
+    severity: Severity::Warning,
+    description: "認証サービスが見つからない",
+};
+
+const NO_LOGIN: Rule = Rule {
+    id: "authentication/no-login",
+    severity: Severity::Warning,
+    description: "ログイン機能が見つからない",
+};
+
+const RULES: &[Rule] = &[NO_AUTH_SERVICE, NO_LOGIN];
+
+// 認証解析
+pub struct AuthenticationAnalyzer;
+
+impl Analyzer for AuthenticationAnalyzer {
+    fn id(&self) -> &str {
+        "authentication"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        let mut auth_service_found = false;
+        let mut login_component_found = false;
+        let mut jwt_usage = false;
+        let mut password_validation = false;
+
+        for file in ctx.index.files(FileKind::Ts) {
+            if let Some(module) = file.module() {
+                // 認証サービスの存在チェック
+                if module.has_name("AuthService")
+                    || module
+                        .imports
+                        .iter()
+                        .any(|i| i.source.ends_with("auth.service"))
+                {
+                    auth_service_found = true;
+                }
+
+                // ログインコンポーネントの存在チェック
+                if module.any_name(|n| n.contains("login") || n.contains("Login")) {
+                    login_component_found = true;
+                }
+
+                // JWT使用チェック
+                if module
+                    .any_name(|n| n.contains("jwt") || n.contains("JWT") || n.contains("token"))
+                {
+                    jwt_usage = true;
+                }
+
+                // パスワードバリデーションチェック
+                if module.any_name(|n| n.contains("password"))
+                    && module.any_name(|n| n.contains("validate") || n.contains("required"))
+                {
+                    password_validation = true;
+                }
+            }
+        }
+
+        result.details.push(format!(
+            "認証サービス: {}",
+            if auth_service_found {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "ログイン機能: {}",
+            if login_component_found {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "JWT/トークン認証: {}",
+            if jwt_usage {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "パスワード検証: {}",
+            if password_validation {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+
+        if !auth_service_found {
+            result.push(NO_AUTH_SERVICE.finding("認証サービスが確認できません"));
+        }
+
+        if !login_component_found {
+            result.push(NO_LOGIN.finding("ログイン機能が確認できません"));
+        }
+
+        Ok(result)
+    }
+}
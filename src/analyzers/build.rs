@@ -0,0 +1,285 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Finding, Rule, Severity};
+use crate::jsonc;
+use crate::table::Table;
+use crate::workspace::{Project, WorkspaceKind};
+
+const CATEGORY: &str = "ビルド設定";
+
+const MISSING_BUDGETS: Rule = Rule {
+    id: "build/missing-budgets",
+    severity: Severity::Warning,
+    description: "本番ビルドにバンドルサイズの budgets が設定されていないアプリケーション",
+};
+
+const OPTIMIZATION_DISABLED: Rule = Rule {
+    id: "build/optimization-disabled",
+    severity: Severity::Warning,
+    description: "本番ビルドで optimization が無効なアプリケーション",
+};
+
+const OUTPUT_HASHING_DISABLED: Rule = Rule {
+    id: "build/output-hashing-disabled",
+    severity: Severity::Warning,
+    description: "本番ビルドで outputHashing が無効 (none または未指定) なアプリケーション",
+};
+
+const RULES: &[Rule] = &[
+    MISSING_BUDGETS,
+    OPTIMIZATION_DISABLED,
+    OUTPUT_HASHING_DISABLED,
+];
+
+// budgets / outputHashing などを持つ Angular のビルダー (Nx の executor を含む)
+const ANGULAR_BUILDERS: &[&str] = &[
+    "@angular-devkit/build-angular:",
+    "@angular/build:",
+    "@nx/angular:",
+    "@nrwl/angular:",
+];
+
+// angular.json / project.json のプロジェクト一覧と本番ビルド設定の検査
+pub struct BuildAnalyzer;
+
+impl Analyzer for BuildAnalyzer {
+    fn id(&self) -> &str {
+        "build"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let Some(workspace) = ctx.index.workspace() else {
+            return Ok(AnalysisResult::skipped(
+                CATEGORY,
+                "angular.json (または nx.json) が見つかりません",
+            ));
+        };
+
+        let mut result = AnalysisResult::new(CATEGORY);
+        // --projects の各プロジェクトではそのプロジェクトの設定だけを対象にする
+        let projects = workspace.projects_in(&ctx.index.root);
+        let kind = match workspace.kind {
+            WorkspaceKind::Nx => "Nx",
+            WorkspaceKind::AngularCli => "Angular CLI",
+        };
+        result.details.push(format!(
+            "ワークスペース: {} ({})",
+            kind,
+            // ワークスペースのルートにあるファイル (プロジェクト別の解析ではルートの外になる)
+            workspace.file.rsplit('/').next().unwrap_or(&workspace.file)
+        ));
+        let count_type = |t: &str| projects.iter().filter(|p| p.project_type == t).count();
+        result.details.push(format!(
+            "プロジェクト数: {} (アプリケーション: {}, ライブラリ: {})",
+            projects.len(),
+            count_type("application"),
+            count_type("library")
+        ));
+        result.details.push(format!(
+            "Service Worker を有効にしたアプリケーション数: {}",
+            projects
+                .iter()
+                .filter(|p| p.build.as_ref().and_then(|b| b.service_worker) == Some(true))
+                .count()
+        ));
+
+        let mut table = Table::new(
+            "プロジェクトとビルド設定",
+            &[
+                "プロジェクト",
+                "種別",
+                "sourceRoot",
+                "ビルダー",
+                "構成",
+                "optimization",
+                "outputHashing",
+                "serviceWorker",
+                "budgets",
+            ],
+        );
+        for project in projects {
+            let source_root = project
+                .source_root
+                .as_deref()
+                .map_or("", |s| ctx.index.relative(s));
+            let Some(build) = &project.build else {
+                table.push_row(vec![
+                    project.name.clone(),
+                    project.project_type.clone(),
+                    source_root.to_string(),
+                ]);
+                continue;
+            };
+            let flag = |value: Option<bool>| value.map_or(String::new(), |v| v.to_string());
+            let budgets: Vec<String> = build
+                .budgets
+                .iter()
+                .map(|b| {
+                    let limits: Vec<&str> = [&b.maximum_warning, &b.maximum_error]
+                        .into_iter()
+                        .flatten()
+                        .map(String::as_str)
+                        .collect();
+                    format!("{}: {}", b.kind, limits.join(" / "))
+                })
+                .collect();
+            table.push_row(vec![
+                project.name.clone(),
+                project.project_type.clone(),
+                source_root.to_string(),
+                build.builder.clone(),
+                build.configuration.clone(),
+                flag(build.optimization),
+                build.output_hashing.clone().unwrap_or_default(),
+                flag(build.service_worker),
+                budgets.join(", "),
+            ]);
+
+            // ライブラリのビルド (ng-packagr) や Angular 以外の executor (node, webpack など) には
+            // これらの設定が無いため、Angular のビルダーでビルドするアプリケーションだけを見る
+            if project.project_type != "application"
+                || !ANGULAR_BUILDERS
+                    .iter()
+                    .any(|b| build.builder.starts_with(b))
+            {
+                continue;
+            }
+            if build.budgets.is_empty() {
+                push_at(
+                    &mut result,
+                    ctx,
+                    project,
+                    MISSING_BUDGETS.finding(format!(
+                        "{} の本番ビルドに budgets が設定されていません",
+                        project.name
+                    )),
+                );
+            }
+            if build.optimization == Some(false) {
+                push_at(
+                    &mut result,
+                    ctx,
+                    project,
+                    OPTIMIZATION_DISABLED.finding(format!(
+                        "{} の本番ビルドで optimization が無効です",
+                        project.name
+                    )),
+                );
+            }
+            if build.output_hashing.as_deref().is_none_or(|h| h == "none") {
+                push_at(
+                    &mut result,
+                    ctx,
+                    project,
+                    OUTPUT_HASHING_DISABLED.finding(format!(
+                        "{} の本番ビルドで outputHashing が無効です (キャッシュされた古いバンドルが配信される恐れ)",
+                        project.name
+                    )),
+                );
+            }
+        }
+        result.tables.push(table);
+        Ok(result)
+    }
+}
+
+// angular.json の projects 内のキー、または project.json の build ターゲットの位置に付ける
+fn push_at(result: &mut AnalysisResult, ctx: &ProjectContext, project: &Project, finding: Finding) {
+    let range = ctx
+        .index
+        .get(&project.file)
+        .and_then(|f| f.content())
+        .and_then(|content| {
+            jsonc::key_range(content, &["projects", &project.name])
+                .or_else(|| jsonc::key_range(content, &["targets", "build"]))
+        });
+    match range {
+        Some(range) => result.push_at(&ctx.index, &project.file, range.start, range.end, finding),
+        None => result.push(finding),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    const ANGULAR_JSON: &str = r#"{
+  "projects": {
+    "shop": {
+      "projectType": "application",
+      "sourceRoot": "apps/shop/src",
+      "architect": {
+        "build": {
+          "builder": "@angular-devkit/build-angular:application",
+          "options": { "outputHashing": "all" },
+          "configurations": { "production": { "serviceWorker": true } }
+        }
+      }
+    },
+    "api": {
+      "projectType": "application",
+      "sourceRoot": "apps/api/src",
+      "architect": { "build": { "builder": "@nx/webpack:webpack" } }
+    }
+  }
+}"#;
+
+    fn analyze(ctx: &ProjectContext) -> AnalysisResult {
+        BuildAnalyzer.analyze(ctx).unwrap()
+    }
+
+    #[test]
+    fn checks_only_angular_builders() {
+        let project = TempProject::new(&[("angular.json", ANGULAR_JSON)]);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Default::default(),
+        };
+        let result = analyze(&ctx);
+        let findings: Vec<&str> = result.findings.iter().map(|f| f.message.as_str()).collect();
+        // webpack でビルドする api は budgets / outputHashing を持たないため指摘しない
+        assert_eq!(
+            findings,
+            ["shop の本番ビルドに budgets が設定されていません"]
+        );
+        let location = result.findings[0].location.as_ref().unwrap();
+        assert_eq!(location.span.start_line, 3);
+        assert_eq!(result.tables[0].rows.len(), 2);
+    }
+
+    #[test]
+    fn project_subset_reports_its_own_build() {
+        let project = TempProject::new(&[
+            ("angular.json", ANGULAR_JSON),
+            ("apps/shop/src/main.ts", ""),
+        ]);
+        let index = project.index();
+        let ctx = ProjectContext {
+            project_path: project.path("apps/shop/src"),
+            index: index.subset(&project.path("apps/shop/src")),
+            config: Default::default(),
+        };
+        let result = analyze(&ctx);
+        assert_eq!(result.tables[0].rows.len(), 1);
+        let row = &result.tables[0].rows[0];
+        assert_eq!(row[0], "shop");
+        assert_eq!(row[6], "all");
+        assert_eq!(row[7], "true");
+        assert!(
+            result
+                .details
+                .contains(&"Service Worker を有効にしたアプリケーション数: 1".to_string())
+        );
+    }
+}
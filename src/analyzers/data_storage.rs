@@ -0,0 +1,70 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::index::FileKind;
+
+const CATEGORY: &str = "データ保持";
+
+const NO_STORAGE: Rule = Rule {
+    id: "data-storage/no-storage",
+    severity: Severity::Warning,
+    description: "localStorage / sessionStorage / IndexedDB のいずれも使われていない",
+};
+
+const RULES: &[Rule] = &[NO_STORAGE];
+
+// データ保持解析
+pub struct DataStorageAnalyzer;
+
+impl Analyzer for DataStorageAnalyzer {
+    fn id(&self) -> &str {
+        "data_storage"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        let mut local_storage_usage = 0;
+        let mut session_storage_usage = 0;
+        let mut indexed_db_usage = 0;
+
+        for file in ctx.index.files(FileKind::Ts) {
+            if let Some(module) = file.module() {
+                if module.has_name("localStorage") {
+                    local_storage_usage += 1;
+                }
+                if module.has_name("sessionStorage") {
+                    session_storage_usage += 1;
+                }
+                if module.has_name("indexedDB") || module.has_name("IndexedDB") {
+                    indexed_db_usage += 1;
+                }
+            }
+        }
+
+        result
+            .details
+            .push(format!("localStorage使用箇所: {}", local_storage_usage));
+        result
+            .details
+            .push(format!("sessionStorage使用箇所: {}", session_storage_usage));
+        result
+            .details
+            .push(format!("IndexedDB使用箇所: {}", indexed_db_usage));
+
+        if local_storage_usage == 0 && session_storage_usage == 0 && indexed_db_usage == 0 {
+            result.push(NO_STORAGE.finding("データ保持機能が確認できません"));
+        }
+
+        Ok(result)
+    }
+}
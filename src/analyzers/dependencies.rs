@@ -0,0 +1,318 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::packages::{Dependency, Packages};
+use crate::table::Table;
+use crate::time::rfc3339_now;
+
+const CATEGORY: &str = "依存パッケージ";
+
+const VERSION_MISMATCH: Rule = Rule {
+    id: "dependencies/angular-version-mismatch",
+    severity: Severity::Warning,
+    description: "@angular/core とバージョンがそろっていない Angular パッケージ",
+};
+
+const END_OF_LIFE: Rule = Rule {
+    id: "dependencies/angular-end-of-life",
+    severity: Severity::Warning,
+    description: "長期サポート (LTS) が終了した Angular のメジャーバージョン",
+};
+
+const RULES: &[Rule] = &[VERSION_MISMATCH, END_OF_LIFE];
+
+// バージョンを表に出すパッケージ
+const REPORTED: &[&str] = &[
+    "@angular/core",
+    "@angular/cli",
+    "@angular/cdk",
+    "@angular/material",
+    "rxjs",
+    "@ngrx/store",
+    "typescript",
+    "zone.js",
+];
+
+// @angular/core と同じバージョンで公開されるフレームワーク本体
+const FRAMEWORK: &[&str] = &[
+    "@angular/animations",
+    "@angular/common",
+    "@angular/compiler",
+    "@angular/compiler-cli",
+    "@angular/elements",
+    "@angular/forms",
+    "@angular/language-service",
+    "@angular/localize",
+    "@angular/platform-browser",
+    "@angular/platform-browser-dynamic",
+    "@angular/platform-server",
+    "@angular/router",
+    "@angular/service-worker",
+    "@angular/upgrade",
+];
+
+// 独自にパッチを出すがメジャーバージョンは @angular/core に合わせるパッケージ
+const TOOLING: &[&str] = &[
+    "@angular/build",
+    "@angular/cdk",
+    "@angular/cli",
+    "@angular/google-maps",
+    "@angular/material",
+    "@angular/ssr",
+    "@angular-devkit/build-angular",
+];
+
+// Angular のメジャーバージョンごとの LTS 終了日 (angular.dev/reference/releases)
+// 表の最古より前のバージョンはすべてサポート終了として扱う
+const LTS_END: &[(u64, &str)] = &[
+    (8, "2020-11-28"),
+    (9, "2021-08-06"),
+    (10, "2021-12-31"),
+    (11, "2022-05-11"),
+    (12, "2022-11-12"),
+    (13, "2023-05-04"),
+    (14, "2023-11-18"),
+    (15, "2024-05-18"),
+    (16, "2024-11-08"),
+    (17, "2025-05-15"),
+    (18, "2025-11-21"),
+    (19, "2026-05-19"),
+    (20, "2026-11-28"),
+    (21, "2027-05-19"),
+];
+
+// package.json とロックファイルから主要パッケージのバージョンとサポート状況を調べる
+pub struct DependenciesAnalyzer;
+
+impl Analyzer for DependenciesAnalyzer {
+    fn id(&self) -> &str {
+        "dependencies"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let Some(packages) = ctx.index.packages() else {
+            return Ok(AnalysisResult::skipped(
+                CATEGORY,
+                "package.json が見つかりません",
+            ));
+        };
+
+        let mut result = AnalysisResult::new(CATEGORY);
+        result.details.push(format!(
+            "package.json: {} (依存 {} 件)",
+            ctx.index.relative(&packages.manifest),
+            packages.dependencies.len()
+        ));
+        match &packages.lock {
+            Some((kind, path)) => result.details.push(format!(
+                "ロックファイル: {} ({})",
+                ctx.index.relative(path),
+                kind.label()
+            )),
+            None => result.details.push(
+                "ロックファイルが見つからないため、インストールされるバージョンは不明です (バージョンの比較とサポート状況の判定は行いません)"
+                    .to_string(),
+            ),
+        }
+
+        let mut table = Table::new(
+            "主要パッケージのバージョン",
+            &["パッケージ", "package.json", "ロックファイル", "区分"],
+        );
+        let mut missing = Vec::new();
+        for name in REPORTED {
+            let Some(dependency) = packages.get(name) else {
+                missing.push(*name);
+                continue;
+            };
+            table.push_row(vec![
+                dependency.name.clone(),
+                dependency.range.clone(),
+                dependency
+                    .installed
+                    .clone()
+                    .unwrap_or_else(|| "不明".to_string()),
+                if dependency.dev {
+                    "devDependencies"
+                } else {
+                    "dependencies"
+                }
+                .to_string(),
+            ]);
+        }
+        if !missing.is_empty() {
+            result
+                .details
+                .push(format!("未導入: {}", missing.join(", ")));
+        }
+        result.tables.push(table);
+
+        let Some(core) = packages.get("@angular/core") else {
+            result
+                .details
+                .push("@angular/core が依存に含まれていません".to_string());
+            return Ok(result);
+        };
+        // package.json の範囲の下限は実際にインストールされるバージョンとは限らないため、
+        // ロックファイルで確定したバージョンだけを比較する
+        let Some(core_version) = core.installed_version() else {
+            result.details.push(format!(
+                "Angular バージョン: 不明 (package.json の範囲: {})",
+                core.range
+            ));
+            return Ok(result);
+        };
+        result
+            .details
+            .push(format!("Angular バージョン: {}", core_version));
+
+        for dependency in &packages.dependencies {
+            let Some(version) = dependency.installed_version() else {
+                continue;
+            };
+            let name = dependency.name.as_str();
+            let mismatch = if FRAMEWORK.contains(&name) {
+                version != core_version
+            } else {
+                TOOLING.contains(&name) && version.major != core_version.major
+            };
+            if mismatch {
+                let offset = manifest_offset(ctx, packages, dependency);
+                result.push_at(
+                    &ctx.index,
+                    &packages.manifest,
+                    offset,
+                    offset,
+                    VERSION_MISMATCH.finding(format!(
+                        "{} {} が @angular/core {} とそろっていません",
+                        name, version, core_version
+                    )),
+                );
+            }
+        }
+
+        let today = &rfc3339_now()[..10];
+        let lts_end = LTS_END
+            .iter()
+            .find(|(major, _)| *major == core_version.major)
+            .map(|(_, date)| *date);
+        match lts_end {
+            Some(date) => result.details.push(format!(
+                "Angular {} の LTS 終了日: {}",
+                core_version.major, date
+            )),
+            None if core_version.major > LTS_END[LTS_END.len() - 1].0 => {
+                result.details.push(format!(
+                    "Angular {} はサポート表に無い新しいバージョンです",
+                    core_version.major
+                ))
+            }
+            None => {}
+        }
+        let ended = match lts_end {
+            Some(date) => date < today,
+            None => core_version.major < LTS_END[0].0,
+        };
+        if ended {
+            let offset = manifest_offset(ctx, packages, core);
+            result.push_at(
+                &ctx.index,
+                &packages.manifest,
+                offset,
+                offset,
+                END_OF_LIFE.finding(format!(
+                    "Angular {} は長期サポートが終了しています{}",
+                    core_version.major,
+                    lts_end.map_or(String::new(), |d| format!(" ({})", d))
+                )),
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+// package.json で依存関係の名前が書かれた位置 (見つからなければ先頭)
+fn manifest_offset(ctx: &ProjectContext, packages: &Packages, dependency: &Dependency) -> usize {
+    ctx.index
+        .get(&packages.manifest)
+        .and_then(|f| f.content())
+        .and_then(|content| content.find(&format!("\"{}\"", dependency.name)))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    const MANIFEST: &str = r#"{
+  "dependencies": {
+    "@angular/core": "^17.3.0",
+    "@angular/common": "^17.3.0",
+    "@angular/router": "^17.0.0"
+  }
+}"#;
+
+    fn analyze(files: &[(&str, &str)]) -> AnalysisResult {
+        let project = TempProject::new(files);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Default::default(),
+        };
+        DependenciesAnalyzer.analyze(&ctx).unwrap()
+    }
+
+    #[test]
+    fn compares_locked_versions() {
+        let lock = "lockfileVersion: '6.0'\n\
+            dependencies:\n  \
+              '@angular/core':\n    \
+                specifier: ^17.3.0\n    \
+                version: 17.3.5(rxjs@7.8.1)\n  \
+              '@angular/common':\n    \
+                specifier: ^17.3.0\n    \
+                version: 17.3.4\n";
+        let result = analyze(&[("package.json", MANIFEST), ("pnpm-lock.yaml", lock)]);
+        assert!(
+            result
+                .details
+                .contains(&"Angular バージョン: 17.3.5".to_string())
+        );
+        // ロックファイルに無い @angular/router は範囲の下限 (17.0.0) で比べない
+        let mismatches: Vec<&str> = result
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == VERSION_MISMATCH.id)
+            .map(|f| f.message.as_str())
+            .collect();
+        assert_eq!(
+            mismatches,
+            ["@angular/common 17.3.4 が @angular/core 17.3.5 とそろっていません"]
+        );
+        let location = result.findings[0].location.as_ref().unwrap();
+        assert_eq!(location.span.start_line, 4);
+    }
+
+    #[test]
+    fn unknown_without_lock_file() {
+        let result = analyze(&[("package.json", MANIFEST)]);
+        assert!(result.findings.is_empty());
+        assert!(
+            result
+                .details
+                .contains(&"Angular バージョン: 不明 (package.json の範囲: ^17.3.0)".to_string())
+        );
+        assert!(result.tables[0].rows.iter().all(|row| row[2] == "不明"));
+    }
+}
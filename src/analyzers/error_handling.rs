@@ -0,0 +1,83 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::index::FileKind;
+
+const CATEGORY: &str = "エラーハンドリング・例外処理";
+
+const NO_GLOBAL_HANDLER: Rule = Rule {
+    id: "error-handling/no-global-handler",
+    severity: Severity::Warning,
+    description: "グローバル ErrorHandler が見つからない",
+};
+
+const RULES: &[Rule] = &[NO_GLOBAL_HANDLER];
+
+// エラーハンドリング解析
+pub struct ErrorHandlingAnalyzer;
+
+impl Analyzer for ErrorHandlingAnalyzer {
+    fn id(&self) -> &str {
+        "error_handling"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        let mut global_error_handler = false;
+        let mut try_catch_blocks = 0;
+        let mut error_interceptor = false;
+
+        for file in ctx.index.files(FileKind::Ts) {
+            if let Some(module) = file.module() {
+                if module.has_name("ErrorHandler") {
+                    global_error_handler = true;
+                }
+
+                if module.any_name(|n| n.starts_with("HttpInterceptor"))
+                    && module.any_name(|n| n.to_ascii_lowercase().contains("error"))
+                {
+                    error_interceptor = true;
+                }
+
+                // try-catchブロックをカウント
+                try_catch_blocks += module.try_blocks.len();
+            }
+        }
+
+        result.details.push(format!(
+            "グローバルエラーハンドラー: {}",
+            if global_error_handler {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result
+            .details
+            .push(format!("try-catchブロック数: {}", try_catch_blocks));
+        result.details.push(format!(
+            "エラーインターセプター: {}",
+            if error_interceptor {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+
+        if !global_error_handler {
+            result.push(NO_GLOBAL_HANDLER.finding("グローバルエラーハンドラーが確認できません"));
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,309 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::graph::Graph;
+use crate::imports::ImportGraph;
+use crate::index::ProjectIndex;
+
+const CATEGORY: &str = "インポート構成";
+
+// 報告する循環参照の上限と、列挙でたどる辺の上限 (大きな強連結成分で爆発しないように)
+const MAX_CYCLES: usize = 100;
+const MAX_STEPS: usize = 1_000_000;
+
+const CIRCULAR_DEPENDENCY: Rule = Rule {
+    id: "imports/circular-dependency",
+    severity: Severity::Error,
+    description: "ファイル間の import が循環している",
+};
+
+const RULES: &[Rule] = &[CIRCULAR_DEPENDENCY];
+
+// ファイル間の import 解析と循環参照の検出
+pub struct ImportsAnalyzer;
+
+impl Analyzer for ImportsAnalyzer {
+    fn id(&self) -> &str {
+        "imports"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+        let imports = ctx.index.imports();
+
+        result
+            .details
+            .push(format!("解析したファイル数: {}", imports.files.len()));
+        result.details.push(format!(
+            "プロジェクト内の import 数: {}",
+            imports.edge_count()
+        ));
+        result.details.push(format!(
+            "解決できなかった import 数 (相対パス・パスエイリアス): {}",
+            imports.unresolved
+        ));
+        // extends で継承しただけの paths は定義元のファイルで数える
+        for config in ctx.index.tsconfigs() {
+            if !config.paths.is_empty() && config.paths_source == config.path {
+                result.details.push(format!(
+                    "パスエイリアス: {} 件 ({})",
+                    config.paths.len(),
+                    ctx.index.relative(&config.path)
+                ));
+            }
+        }
+
+        let (cycles, truncated) = cycles(imports);
+        result.details.push(format!("循環参照数: {}", cycles.len()));
+        if truncated {
+            result.details.push(format!(
+                "循環参照が多いため一部 (最大 {} 件) のみ報告しています",
+                MAX_CYCLES
+            ));
+        }
+
+        for cycle in &cycles {
+            let chain: Vec<&str> = cycle
+                .iter()
+                .chain(cycle.first())
+                .map(|&i| ctx.index.relative(&imports.files[i]))
+                .collect();
+            let finding = CIRCULAR_DEPENDENCY.finding(format!("循環参照: {}", chain.join(" → ")));
+
+            // 循環の先頭ファイルで次のファイルを import している位置
+            let (from, to) = (cycle[0], cycle[1 % cycle.len()]);
+            let path = &imports.files[from];
+            let range = imports.edges[from]
+                .iter()
+                .find(|e| e.to == to)
+                .map(|e| e.range);
+            match range {
+                Some(range) => result.push_at(&ctx.index, path, range.start, range.end, finding),
+                None => result.push(finding),
+            }
+        }
+
+        result.graphs.push(to_graph(imports, &ctx.index));
+        Ok(result)
+    }
+}
+
+// 強連結成分ごとに単純閉路を列挙する。各閉路は最小の添字から始まる
+fn cycles(imports: &ImportGraph) -> (Vec<Vec<usize>>, bool) {
+    let component = strongly_connected_components(imports);
+    let n = imports.files.len();
+    let mut cycles = Vec::new();
+    let mut steps = 0;
+
+    for start in 0..n {
+        let in_cycle = |node: usize| node >= start && component[node] == component[start];
+        let mut path = vec![start];
+        let mut on_path = vec![false; n];
+        on_path[start] = true;
+        // (ノード, 次に調べる辺の添字)
+        let mut stack = vec![(start, 0usize)];
+        while let Some((node, next)) = stack.pop() {
+            let Some(to) = imports.edges[node].get(next).map(|e| e.to) else {
+                on_path[node] = false;
+                path.pop();
+                continue;
+            };
+            stack.push((node, next + 1));
+            steps += 1;
+            if steps > MAX_STEPS {
+                return (cycles, true);
+            }
+            if to == start {
+                if cycles.len() == MAX_CYCLES {
+                    return (cycles, true);
+                }
+                cycles.push(path.clone());
+            } else if in_cycle(to) && !on_path[to] {
+                on_path[to] = true;
+                path.push(to);
+                stack.push((to, 0));
+            }
+        }
+    }
+    (cycles, false)
+}
+
+// Tarjan のアルゴリズム (再帰を使わない版)。添字ごとの成分番号を返す
+fn strongly_connected_components(imports: &ImportGraph) -> Vec<usize> {
+    let n = imports.files.len();
+    let mut index_of = vec![usize::MAX; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut component = vec![usize::MAX; n];
+    let mut next_index = 0;
+    let mut next_component = 0;
+
+    for root in 0..n {
+        if index_of[root] != usize::MAX {
+            continue;
+        }
+        let mut work = vec![(root, 0usize)];
+        while let Some((node, edge)) = work.pop() {
+            if edge == 0 && index_of[node] == usize::MAX {
+                index_of[node] = next_index;
+                low[node] = next_index;
+                next_index += 1;
+                stack.push(node);
+                on_stack[node] = true;
+            }
+            if let Some(to) = imports.edges[node].get(edge).map(|e| e.to) {
+                work.push((node, edge + 1));
+                if index_of[to] == usize::MAX {
+                    work.push((to, 0));
+                } else if on_stack[to] {
+                    low[node] = low[node].min(index_of[to]);
+                }
+                continue;
+            }
+            // 全ての辺を調べ終えたら親に low を伝える
+            if low[node] == index_of[node] {
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component[member] = next_component;
+                    if member == node {
+                        break;
+                    }
+                }
+                next_component += 1;
+            }
+            if let Some(&(parent, _)) = work.last() {
+                low[parent] = low[parent].min(low[node]);
+            }
+        }
+    }
+    component
+}
+
+// import を持つファイルだけをノードにする
+fn to_graph(imports: &ImportGraph, index: &ProjectIndex) -> Graph {
+    let mut graph = Graph::new("imports", "ファイル間の import");
+    for (from, edges) in imports.edges.iter().enumerate() {
+        for edge in edges {
+            let (from_path, to_path) = (&imports.files[from], &imports.files[edge.to]);
+            graph.add_node(from_path, index.relative(from_path), "file");
+            graph.add_node(to_path, index.relative(to_path), "file");
+            graph.add_edge(from_path, to_path, "");
+        }
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::imports::{Import, collect_imports};
+    use crate::testing::TempProject;
+    use crate::ts::TextRange;
+
+    // 添字の組から import グラフを作る
+    fn graph(n: usize, edges: &[(usize, usize)]) -> ImportGraph {
+        let mut graph = ImportGraph {
+            files: (0..n).map(|i| format!("/p/{}.ts", i)).collect(),
+            edges: vec![Vec::new(); n],
+            unresolved: 0,
+        };
+        for &(from, to) in edges {
+            graph.edges[from].push(Import {
+                to,
+                specifier: String::new(),
+                range: TextRange::default(),
+            });
+        }
+        graph
+    }
+
+    #[test]
+    fn components() {
+        // 0 → 1 → 2 → 0 の循環、3 は自己ループ、4 → 5 は循環しない
+        let imports = graph(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 3), (4, 5), (5, 1)]);
+        let component = strongly_connected_components(&imports);
+        assert_eq!(component[0], component[1]);
+        assert_eq!(component[1], component[2]);
+        let distinct: HashSet<usize> = component.iter().copied().collect();
+        assert_eq!(distinct.len(), 4);
+        assert_ne!(component[3], component[0]);
+        assert_ne!(component[4], component[5]);
+        assert_ne!(component[5], component[0]);
+    }
+
+    #[test]
+    fn components_of_long_chain() {
+        // 再帰しないのでスタックを溢れさせない
+        let n = 100_000;
+        let mut edges: Vec<_> = (0..n - 1).map(|i| (i, i + 1)).collect();
+        edges.push((n - 1, 0));
+        let component = strongly_connected_components(&graph(n, &edges));
+        assert!(component.iter().all(|&c| c == component[0]));
+    }
+
+    #[test]
+    fn simple_cycles() {
+        // 0 ⇄ 1、1 → 2 → 0、3 の自己ループ、4 は循環しない
+        let imports = graph(5, &[(0, 1), (1, 0), (1, 2), (2, 0), (3, 3), (4, 0)]);
+        let (cycles, truncated) = cycles(&imports);
+        assert!(!truncated);
+        assert_eq!(cycles, vec![vec![0, 1], vec![0, 1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn cycles_start_at_smallest_index() {
+        let imports = graph(3, &[(2, 1), (1, 0), (0, 2)]);
+        assert_eq!(cycles(&imports).0, vec![vec![0, 2, 1]]);
+    }
+
+    #[test]
+    fn cycles_are_truncated() {
+        // 完全グラフの単純閉路は上限を大きく超える
+        let n = 8;
+        let edges: Vec<_> = (0..n)
+            .flat_map(|a| (0..n).filter(move |&b| b != a).map(move |b| (a, b)))
+            .collect();
+        let (cycles, truncated) = cycles(&graph(n, &edges));
+        assert!(truncated);
+        assert_eq!(cycles.len(), MAX_CYCLES);
+    }
+
+    #[test]
+    fn collect_skips_type_only_and_dynamic_imports() {
+        let project = TempProject::new(&[
+            (
+                "src/a.ts",
+                "import { B } from './b';\nimport type { C } from './c';\nimport './b';\nexport const a = () => import('./c');\n",
+            ),
+            (
+                "src/b.ts",
+                "import { A } from './a';\nimport { X } from './missing';\nimport { of } from 'rxjs';\n",
+            ),
+            ("src/c.ts", "import { A } from './a';\n"),
+        ]);
+        let index = project.index();
+        let imports = collect_imports(&index);
+        let position = |name: &str| imports.position(&project.path(name)).unwrap();
+        let targets = |name: &str| -> Vec<usize> {
+            imports.edges[position(name)].iter().map(|e| e.to).collect()
+        };
+
+        assert_eq!(targets("src/a.ts"), [position("src/b.ts")]);
+        assert_eq!(targets("src/b.ts"), [position("src/a.ts")]);
+        assert_eq!(targets("src/c.ts"), [position("src/a.ts")]);
+        assert_eq!(imports.unresolved, 1);
+        assert_eq!(cycles(&imports).0.len(), 1);
+    }
+}
@@ -0,0 +1,375 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::index::FileKind;
+use crate::table::Table;
+use crate::template::{AttributeKind, Node};
+use crate::ts::TextRange;
+
+const CATEGORY: &str = "standalone・制御フロー移行";
+
+const NON_STANDALONE_COMPONENT: Rule = Rule {
+    id: "migration/non-standalone-component",
+    severity: Severity::Warning,
+    description: "standalone になっていないコンポーネント",
+};
+
+const NG_MODULE: Rule = Rule {
+    id: "migration/ng-module",
+    severity: Severity::Warning,
+    description: "残っている NgModule",
+};
+
+const STRUCTURAL_DIRECTIVE: Rule = Rule {
+    id: "migration/structural-directive",
+    severity: Severity::Warning,
+    description: "*ngIf / *ngFor / ngSwitch を使っているテンプレート (@if / @for / @switch へ移行可能)",
+};
+
+const BOOTSTRAP_MODULE: Rule = Rule {
+    id: "migration/bootstrap-module",
+    severity: Severity::Warning,
+    description: "bootstrapModule による起動 (bootstrapApplication へ移行可能)",
+};
+
+const ROUTER_MODULE: Rule = Rule {
+    id: "migration/router-module-for-root",
+    severity: Severity::Warning,
+    description: "RouterModule.forRoot によるルーター設定 (provideRouter へ移行可能)",
+};
+
+const RULES: &[Rule] = &[
+    NON_STANDALONE_COMPONENT,
+    NG_MODULE,
+    STRUCTURAL_DIRECTIVE,
+    BOOTSTRAP_MODULE,
+    ROUTER_MODULE,
+];
+
+// 組み込み制御フロー (@if 等) に置き換えられる構造ディレクティブ
+const STRUCTURAL_DIRECTIVES: &[&str] = &["ngIf", "ngFor", "ngSwitchCase", "ngSwitchDefault"];
+
+const CONTROL_FLOW_BLOCKS: &[&str] = &["if", "for", "switch"];
+
+// standalone API が使えるようになったバージョン
+const STANDALONE_SINCE: u64 = 14;
+// 組み込み制御フローが使えるようになったバージョン
+const CONTROL_FLOW_SINCE: u64 = 17;
+
+// NgModule から standalone API へ、構造ディレクティブから組み込み制御フローへの移行状況
+pub struct MigrationAnalyzer;
+
+impl Analyzer for MigrationAnalyzer {
+    fn id(&self) -> &str {
+        "migration"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let angular = ctx
+            .index
+            .packages()
+            .and_then(|p| p.get("@angular/core"))
+            .and_then(|d| d.version());
+        if let Some(version) = angular.filter(|v| v.major < STANDALONE_SINCE) {
+            return Ok(AnalysisResult::skipped(
+                CATEGORY,
+                &format!(
+                    "Angular {} は standalone API に対応していません ({} 以降)",
+                    version, STANDALONE_SINCE
+                ),
+            ));
+        }
+
+        let mut result = AnalysisResult::new(CATEGORY);
+        let mut table = Table::new("移行状況", &["項目", "移行済み", "未移行", "進捗"]);
+
+        let components = ctx.index.components();
+        let mut standalone = 0;
+        for component in components {
+            if component.is_standalone(angular) {
+                standalone += 1;
+                continue;
+            }
+            result.push_at(
+                &ctx.index,
+                &component.file,
+                component.range.start,
+                component.range.start,
+                NON_STANDALONE_COMPONENT
+                    .finding(format!("{} は standalone ではありません", component.name)),
+            );
+        }
+        push_progress(
+            &mut table,
+            "standalone コンポーネント",
+            standalone,
+            components.len() - standalone,
+        );
+        result.details.push(format!(
+            "standalone コンポーネントの割合: {}",
+            percent(standalone, components.len())
+        ));
+
+        let mut modules = 0;
+        let mut bootstrap = (0, 0);
+        let mut router = (0, 0);
+        for file in ctx.index.files(FileKind::Ts) {
+            let Some(module) = file.module() else {
+                continue;
+            };
+            for class in module.decorated_classes("NgModule") {
+                modules += 1;
+                result.push_at(
+                    &ctx.index,
+                    &file.path,
+                    class.range.start,
+                    class.range.start,
+                    NG_MODULE.finding(format!("NgModule {} が残っています", class.name)),
+                );
+            }
+
+            bootstrap.0 += module.calls_to("bootstrapApplication").count();
+            for call in module.method_calls("bootstrapModule") {
+                bootstrap.1 += 1;
+                result.push_at(
+                    &ctx.index,
+                    &file.path,
+                    call.range.start,
+                    call.range.end,
+                    BOOTSTRAP_MODULE.finding(
+                        "bootstrapModule で起動しています (bootstrapApplication へ移行可能)",
+                    ),
+                );
+            }
+
+            router.0 += module.calls_to("provideRouter").count();
+            for call in module.calls_to("RouterModule.forRoot") {
+                router.1 += 1;
+                result.push_at(
+                    &ctx.index,
+                    &file.path,
+                    call.range.start,
+                    call.range.end,
+                    ROUTER_MODULE
+                        .finding("RouterModule.forRoot でルーターを設定しています (provideRouter へ移行可能)"),
+                );
+            }
+        }
+        result
+            .details
+            .push(format!("残っている NgModule 数: {}", modules));
+
+        // 構造ディレクティブを 1 つでも使うテンプレートを未移行とし、ファイルごとに 1 件指摘する
+        let control_flow_available = angular.is_none_or(|v| v.major >= CONTROL_FLOW_SINCE);
+        let mut templates = (0, 0);
+        // @if 等も構造ディレクティブも使わないテンプレートは移行の対象外として数だけ出す
+        let mut without_control_flow = 0;
+        for component_template in ctx.index.templates() {
+            let Some(template) = &component_template.template else {
+                continue;
+            };
+            let mut directives: Vec<(&str, TextRange)> = Vec::new();
+            let mut blocks = 0;
+            template.walk(&mut |node| match node {
+                Node::Element(element) => {
+                    for attribute in &element.attributes {
+                        let structural = attribute.kind == AttributeKind::Structural
+                            && STRUCTURAL_DIRECTIVES.contains(&attribute.target.as_str());
+                        if structural
+                            || (attribute.is_property_binding() && attribute.target == "ngSwitch")
+                        {
+                            directives.push((attribute.name.as_str(), attribute.range));
+                        }
+                    }
+                }
+                Node::Block(block) if CONTROL_FLOW_BLOCKS.contains(&block.name.as_str()) => {
+                    blocks += 1
+                }
+                _ => {}
+            });
+
+            if directives.is_empty() {
+                if blocks > 0 {
+                    templates.0 += 1;
+                } else {
+                    without_control_flow += 1;
+                }
+                continue;
+            }
+            templates.1 += 1;
+            if !control_flow_available {
+                continue;
+            }
+            let mut names: Vec<&str> = directives.iter().map(|(name, _)| *name).collect();
+            names.sort_unstable();
+            names.dedup();
+            result.push_at(
+                &ctx.index,
+                &component_template.file,
+                directives[0].1.start,
+                directives[0].1.end,
+                STRUCTURAL_DIRECTIVE.finding(format!(
+                    "{} のテンプレートが構造ディレクティブを {} 箇所で使っています ({})",
+                    component_template.component,
+                    directives.len(),
+                    names.join(", ")
+                )),
+            );
+        }
+        result.details.push(format!(
+            "制御フローを使わないテンプレート数: {} (移行状況の集計の対象外)",
+            without_control_flow
+        ));
+
+        let control_flow = "制御フロー (@if / @for / @switch のみのテンプレート)";
+        if control_flow_available {
+            push_progress(&mut table, control_flow, templates.0, templates.1);
+        } else {
+            // 移行できないバージョンでは指摘と同様に未移行として数えない
+            result.details.push(format!(
+                "組み込み制御フロー (@if / @for) は Angular {} 以降で使えます",
+                CONTROL_FLOW_SINCE
+            ));
+            table.push_row(vec![
+                control_flow.to_string(),
+                "-".to_string(),
+                "-".to_string(),
+                format!("対象外 (Angular {} 以降)", CONTROL_FLOW_SINCE),
+            ]);
+        }
+        push_progress(&mut table, "bootstrapApplication", bootstrap.0, bootstrap.1);
+        push_progress(&mut table, "provideRouter", router.0, router.1);
+        result.tables.push(table);
+        Ok(result)
+    }
+}
+
+fn push_progress(table: &mut Table, label: &str, migrated: usize, remaining: usize) {
+    table.push_row(vec![
+        label.to_string(),
+        migrated.to_string(),
+        remaining.to_string(),
+        percent(migrated, migrated + remaining),
+    ]);
+}
+
+// 対象が無い場合は "-"
+fn percent(part: usize, total: usize) -> String {
+    if total == 0 {
+        return "-".to_string();
+    }
+    format!("{}%", part * 100 / total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    const COMPONENTS: &[(&str, &str)] = &[
+        (
+            "src/main.ts",
+            "platformBrowserDynamic().bootstrapModule(AppModule);\nprovideRouter(routes);\n",
+        ),
+        (
+            "src/app.module.ts",
+            "@NgModule({ imports: [RouterModule.forRoot(routes)] })\nexport class AppModule {}\n",
+        ),
+        (
+            "src/a.component.ts",
+            "@Component({ selector: 'a', standalone: true, template: '<p *ngIf=\"x\">{{ x }}</p>' })\nexport class A {}\n",
+        ),
+        (
+            "src/b.component.ts",
+            "@Component({ selector: 'b', standalone: true, template: '@if (x) { <p>{{ x }}</p> }' })\nexport class B {}\n",
+        ),
+        (
+            "src/c.component.ts",
+            "@Component({ selector: 'c', standalone: false, template: '<p>c</p>' })\nexport class C {}\n",
+        ),
+    ];
+
+    fn analyze(angular: &str) -> AnalysisResult {
+        let manifest = format!(
+            "{{ \"dependencies\": {{ \"@angular/core\": \"{}\" }} }}",
+            angular
+        );
+        let mut files = COMPONENTS.to_vec();
+        files.push(("package.json", &manifest));
+        let project = TempProject::new(&files);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Default::default(),
+        };
+        MigrationAnalyzer.analyze(&ctx).unwrap()
+    }
+
+    fn count(result: &AnalysisResult, rule: &Rule) -> usize {
+        result
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == rule.id)
+            .count()
+    }
+
+    #[test]
+    fn progress_table() {
+        let result = analyze("17.3.0");
+        let rows: Vec<Vec<&str>> = result.tables[0]
+            .rows
+            .iter()
+            .map(|row| row.iter().map(String::as_str).collect())
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ["standalone コンポーネント", "2", "1", "66%"],
+                [
+                    "制御フロー (@if / @for / @switch のみのテンプレート)",
+                    "1",
+                    "1",
+                    "50%"
+                ],
+                ["bootstrapApplication", "0", "1", "0%"],
+                ["provideRouter", "1", "1", "50%"],
+            ]
+        );
+        assert_eq!(count(&result, &STRUCTURAL_DIRECTIVE), 1);
+        assert_eq!(count(&result, &NON_STANDALONE_COMPONENT), 1);
+        assert!(result.details.contains(
+            &"制御フローを使わないテンプレート数: 1 (移行状況の集計の対象外)".to_string()
+        ));
+    }
+
+    #[test]
+    fn control_flow_not_counted_before_angular_17() {
+        let result = analyze("16.2.0");
+        assert_eq!(count(&result, &STRUCTURAL_DIRECTIVE), 0);
+        assert_eq!(
+            result.tables[0].rows[1],
+            [
+                "制御フロー (@if / @for / @switch のみのテンプレート)",
+                "-",
+                "-",
+                "対象外 (Angular 17 以降)"
+            ]
+        );
+    }
+
+    #[test]
+    fn skipped_before_standalone_api() {
+        let result = analyze("13.0.0");
+        assert!(result.findings.is_empty());
+        assert!(result.tables.is_empty());
+    }
+}
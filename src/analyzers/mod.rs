@@ -0,0 +1,64 @@
+mod api;
+mod architecture;
+mod authentication;
+mod build;
+mod data_storage;
+mod dependencies;
+mod error_handling;
+mod imports;
+mod migration;
+mod modules;
+mod nx;
+mod performance;
+mod routing;
+mod rxjs;
+mod security;
+mod session_management;
+mod state_management;
+mod ui_design_system;
+mod ui_screens;
+
+pub use api::ApiAnalyzer;
+pub use architecture::ArchitectureAnalyzer;
+pub use authentication::AuthenticationAnalyzer;
+pub use build::BuildAnalyzer;
+pub use data_storage::DataStorageAnalyzer;
+pub use dependencies::DependenciesAnalyzer;
+pub use error_handling::ErrorHandlingAnalyzer;
+pub use imports::ImportsAnalyzer;
+pub use migration::MigrationAnalyzer;
+pub use modules::ModulesAnalyzer;
+pub use nx::NxAnalyzer;
+pub use performance::PerformanceAnalyzer;
+pub use routing::RoutingAnalyzer;
+pub use rxjs::RxjsAnalyzer;
+pub use security::SecurityAnalyzer;
+pub use session_management::SessionManagementAnalyzer;
+pub use state_management::StateManagementAnalyzer;
+pub use ui_design_system::UiDesignSystemAnalyzer;
+pub use ui_screens::UiScreensAnalyzer;
+
+use crate::analyzer::AnalyzerRegistry;
+
+pub fn register_defaults(registry: &mut AnalyzerRegistry) {
+    registry
+        .register(UiScreensAnalyzer)
+        .register(DataStorageAnalyzer)
+        .register(ApiAnalyzer)
+        .register(AuthenticationAnalyzer)
+        .register(SessionManagementAnalyzer)
+        .register(SecurityAnalyzer)
+        .register(StateManagementAnalyzer)
+        .register(RoutingAnalyzer)
+        .register(UiDesignSystemAnalyzer)
+        .register(ErrorHandlingAnalyzer)
+        .register(PerformanceAnalyzer)
+        .register(ModulesAnalyzer)
+        .register(ImportsAnalyzer)
+        .register(ArchitectureAnalyzer)
+        .register(NxAnalyzer)
+        .register(BuildAnalyzer)
+        .register(DependenciesAnalyzer)
+        .register(MigrationAnalyzer)
+        .register(RxjsAnalyzer);
+}
@@ -0,0 +1,355 @@
+use std::collections::HashMap;
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::config::DEFAULT_MAX_IMPORTS;
+use crate::finding::{Rule, Severity};
+use crate::graph::{Graph, GraphNode};
+use crate::index::{FileKind, ProjectIndex};
+use crate::ts::{Expr, ExprKind, Module, TextRange};
+
+const CATEGORY: &str = "モジュール構成";
+
+const EXCESSIVE_IMPORTS: Rule = Rule {
+    id: "modules/excessive-imports",
+    severity: Severity::Warning,
+    description: "imports が多すぎる NgModule・コンポーネント (20 件超)",
+};
+
+const RULES: &[Rule] = &[EXCESSIVE_IMPORTS];
+
+// Angular のデコレーターとグラフ上の種別
+const DECORATORS: &[(&str, &str)] = &[
+    ("NgModule", "ngmodule"),
+    ("Component", "component"),
+    ("Directive", "directive"),
+    ("Pipe", "pipe"),
+];
+
+// NgModule・standalone コンポーネントの依存関係解析
+pub struct ModulesAnalyzer;
+
+impl Analyzer for ModulesAnalyzer {
+    fn id(&self) -> &str {
+        "modules"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+        let declarations = collect_declarations(&ctx.index);
+        let graph = module_graph(&ctx.index, &declarations);
+
+        let module_count = declarations
+            .values()
+            .filter(|d| d.kind == "ngmodule")
+            .count();
+        let angular = ctx
+            .index
+            .packages()
+            .and_then(|p| p.get("@angular/core"))
+            .and_then(|d| d.version());
+        let standalone_count = ctx
+            .index
+            .components()
+            .iter()
+            .filter(|c| c.is_standalone(angular))
+            .count();
+        result.details.push(format!("NgModule数: {}", module_count));
+        result
+            .details
+            .push(format!("standaloneコンポーネント数: {}", standalone_count));
+        result.details.push(format!(
+            "依存関係 (declarations/imports/exports) の数: {}",
+            graph.edges().len()
+        ));
+
+        // imports の多いものから指摘する
+        let mut fan_out: Vec<(&GraphNode, usize)> = graph
+            .nodes()
+            .iter()
+            .map(|node| {
+                let count = graph
+                    .edges_from(&node.id)
+                    .filter(|e| e.label == "imports")
+                    .count();
+                (node, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        fan_out.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.id.cmp(&b.0.id)));
+        if let Some((node, count)) = fan_out.first() {
+            result
+                .details
+                .push(format!("imports が最も多い: {} ({}件)", node.label, count));
+        }
+
+        // これを超える imports を持つモジュール・コンポーネントを指摘する
+        let max_imports = ctx.config.max_imports.unwrap_or(DEFAULT_MAX_IMPORTS);
+        for (node, count) in fan_out.iter().filter(|(_, count)| *count > max_imports) {
+            let declaration = &declarations[&node.id];
+            let finding = EXCESSIVE_IMPORTS.finding(format!(
+                "{} が {} 件を imports しています (上限 {} 件)",
+                node.label, count, max_imports
+            ));
+            result.push_at(
+                &ctx.index,
+                &declaration.file,
+                declaration.range.start,
+                declaration.range.start,
+                finding,
+            );
+        }
+
+        result.graphs.push(graph);
+        Ok(result)
+    }
+}
+
+// デコレーター付きクラスの宣言
+struct Declaration {
+    kind: &'static str,
+    file: String,
+    range: TextRange,
+}
+
+// 別ファイルの同名クラスを区別するため "ファイル#クラス名" をキーにする
+fn declaration_key(path: &str, name: &str) -> String {
+    format!("{}#{}", path, name)
+}
+
+fn collect_declarations(index: &ProjectIndex) -> HashMap<String, Declaration> {
+    let mut declarations = HashMap::new();
+    for file in index.files(FileKind::Ts) {
+        let Some(module) = file.module() else {
+            continue;
+        };
+        for class in &module.classes {
+            for &(decorator, kind) in DECORATORS {
+                if class.decorator(decorator).is_none() {
+                    continue;
+                }
+                declarations.insert(
+                    declaration_key(&file.path, &class.name),
+                    Declaration {
+                        kind,
+                        file: file.path.clone(),
+                        range: class.range,
+                    },
+                );
+            }
+        }
+    }
+    declarations
+}
+
+// imports 等に並んだ名前の宣言のキー。同じファイルのクラス、import 元のクラス、
+// プロジェクト内で一意な同名クラスの順に探し、見つからなければ外部のクラスとして名前のまま返す
+fn resolve_declaration(
+    index: &ProjectIndex,
+    declarations: &HashMap<String, Declaration>,
+    by_name: &HashMap<&str, Vec<&str>>,
+    path: &str,
+    module: &Module,
+    name: &str,
+) -> String {
+    let local = declaration_key(path, name);
+    if declarations.contains_key(&local) {
+        return local;
+    }
+    if let Some(import) = module
+        .imports
+        .iter()
+        .find(|i| i.names.iter().any(|n| n == name))
+        && let Some(target) = index.resolve_import(path, &import.source)
+    {
+        let key = declaration_key(&target.path, name);
+        if declarations.contains_key(&key) {
+            return key;
+        }
+    }
+    match by_name.get(name).map(Vec::as_slice) {
+        Some([key]) => key.to_string(),
+        _ => name.to_string(),
+    }
+}
+
+fn module_graph(index: &ProjectIndex, declarations: &HashMap<String, Declaration>) -> Graph {
+    let mut graph = Graph::new("modules", "NgModule・standalone コンポーネントの依存関係");
+    let mut by_name: HashMap<&str, Vec<&str>> = HashMap::new();
+    for key in declarations.keys() {
+        let name = key.rsplit_once('#').map_or(key.as_str(), |(_, name)| name);
+        by_name.entry(name).or_default().push(key);
+    }
+    let kind_of = |key: &str| declarations.get(key).map_or("external", |d| d.kind);
+
+    for file in index.files(FileKind::Ts) {
+        let Some(module) = file.module() else {
+            continue;
+        };
+        for class in &module.classes {
+            let (decorator, kind, keys): (_, _, &[&str]) =
+                if let Some(decorator) = class.decorator("NgModule") {
+                    (
+                        decorator,
+                        "ngmodule",
+                        &["declarations", "imports", "exports"],
+                    )
+                } else if let Some(decorator) = class.decorator("Component") {
+                    (decorator, "component", &["imports"])
+                } else {
+                    continue;
+                };
+            let id = declaration_key(&file.path, &class.name);
+            graph.add_node(&id, &class.name, kind);
+
+            for key in keys {
+                let Some(value) = decorator.args.first().and_then(|m| m.get(key)) else {
+                    continue;
+                };
+                let label = if *key == "declarations" {
+                    "declares"
+                } else {
+                    key
+                };
+                for name in referenced_names(module, value, 0) {
+                    let target = resolve_declaration(
+                        index,
+                        declarations,
+                        &by_name,
+                        &file.path,
+                        module,
+                        &name,
+                    );
+                    graph.add_node(&target, &name, kind_of(&target));
+                    graph.add_edge(&id, &target, label);
+                }
+            }
+        }
+    }
+    graph
+}
+
+// imports 等の配列に並んだクラス名。同じファイル内の配列定数とスプレッドは展開する
+fn referenced_names(module: &Module, expr: &Expr, depth: usize) -> Vec<String> {
+    let mut names = Vec::new();
+    for item in expr.items() {
+        match &item.kind {
+            ExprKind::Spread(inner) => names.extend(referenced_names(module, inner, depth)),
+            ExprKind::Ident(name) => match module.variable(name).and_then(|v| v.init.as_ref()) {
+                Some(init) if depth < 4 && init.as_array().is_some() => {
+                    names.extend(referenced_names(module, init, depth + 1))
+                }
+                _ => names.push(name.clone()),
+            },
+            // RouterModule.forRoot(routes) → RouterModule、forwardRef(() => X) → X
+            ExprKind::Call { callee, args } => match callee.path().as_deref() {
+                Some("forwardRef") => {
+                    if let Some(ExprKind::Function { body: Some(body) }) =
+                        args.first().map(|a| &a.kind)
+                    {
+                        names.extend(body.path());
+                    }
+                }
+                Some(path) => {
+                    let name = path.rsplit_once('.').map_or(path, |(object, _)| object);
+                    names.push(name.to_string());
+                }
+                None => {}
+            },
+            _ => names.extend(item.path()),
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::testing::TempProject;
+
+    #[test]
+    fn same_class_name_in_different_files() {
+        let project = TempProject::new(&[
+            (
+                "src/admin/user.component.ts",
+                "@Component({ selector: 'admin-user', standalone: false })\nexport class UserComponent {}\n",
+            ),
+            (
+                "src/shop/user.component.ts",
+                "@Component({ selector: 'shop-user', imports: [PriceComponent] })\nexport class UserComponent {}\n",
+            ),
+            (
+                "src/shop/price.component.ts",
+                "@Component({ selector: 'shop-price' })\nexport class PriceComponent {}\n",
+            ),
+            (
+                "src/admin/admin.module.ts",
+                "import { UserComponent } from './user.component';\n@NgModule({ declarations: [UserComponent], imports: [CommonModule] })\nexport class AdminModule {}\n",
+            ),
+        ]);
+        let index = project.index();
+        let declarations = collect_declarations(&index);
+        assert_eq!(declarations.len(), 4);
+
+        let graph = module_graph(&index, &declarations);
+        let key = |path: &str, name: &str| declaration_key(&project.path(path), name);
+        let edges: Vec<(&str, &str, &str)> = graph
+            .edges()
+            .iter()
+            .map(|e| (e.from.as_str(), e.to.as_str(), e.label.as_str()))
+            .collect();
+        let admin = key("src/admin/admin.module.ts", "AdminModule");
+        let admin_user = key("src/admin/user.component.ts", "UserComponent");
+        let shop_user = key("src/shop/user.component.ts", "UserComponent");
+        // import 先が無くてもプロジェクト内で一意な名前なら解決する
+        let price = key("src/shop/price.component.ts", "PriceComponent");
+        assert_eq!(
+            edges,
+            [
+                (admin.as_str(), admin_user.as_str(), "declares"),
+                (admin.as_str(), "CommonModule", "imports"),
+                (shop_user.as_str(), price.as_str(), "imports"),
+            ]
+        );
+        let external = graph
+            .nodes()
+            .iter()
+            .find(|n| n.id == "CommonModule")
+            .unwrap();
+        assert_eq!(external.kind, "external");
+    }
+
+    #[test]
+    fn excessive_imports_limit_from_config() {
+        let project = TempProject::new(&[(
+            "src/app.module.ts",
+            "@NgModule({ imports: [CommonModule, FormsModule] })\nexport class AppModule {}\n",
+        )]);
+        let analyze = |max_imports| {
+            let ctx = ProjectContext {
+                project_path: project.root.clone(),
+                index: project.index(),
+                config: Config {
+                    max_imports,
+                    ..Default::default()
+                },
+            };
+            ModulesAnalyzer.analyze(&ctx).unwrap().findings
+        };
+        assert!(analyze(None).is_empty());
+        let findings = analyze(Some(1));
+        assert_eq!(
+            findings[0].message,
+            "AppModule が 2 件を imports しています (上限 1 件)"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::graph::Graph;
+use crate::table::Table;
+use crate::workspace::{Project, WorkspaceKind};
+
+const CATEGORY: &str = "Nx モジュール境界";
+
+const ENFORCE_MODULE_BOUNDARIES: Rule = Rule {
+    id: "nx/enforce-module-boundaries",
+    severity: Severity::Error,
+    description: "タグの依存制約 (depConstraints) に反するプロジェクト間の import",
+};
+
+const RULES: &[Rule] = &[ENFORCE_MODULE_BOUNDARIES];
+
+// Nx ワークスペースのプロジェクト一覧とタグによる依存制約の検査
+pub struct NxAnalyzer;
+
+impl Analyzer for NxAnalyzer {
+    fn id(&self) -> &str {
+        "nx"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let workspace = ctx.index.workspace();
+        let Some(workspace) = workspace.filter(|w| w.kind == WorkspaceKind::Nx) else {
+            return Ok(AnalysisResult::skipped(
+                CATEGORY,
+                "nx.json が見つからないため Nx ワークスペースではありません",
+            ));
+        };
+
+        let mut result = AnalysisResult::new(CATEGORY);
+        // --projects の各プロジェクトではそのプロジェクトだけを一覧・検査の対象にする
+        let projects = workspace.projects_in(&ctx.index.root);
+        let count_type = |t: &str| projects.iter().filter(|p| p.project_type == t).count();
+        result.details.push(format!(
+            "Nx プロジェクト数: {} (アプリケーション: {}, ライブラリ: {})",
+            projects.len(),
+            count_type("application"),
+            count_type("library")
+        ));
+        result.details.push(format!(
+            "タグの無いプロジェクト数: {}",
+            projects.iter().filter(|p| p.tags.is_empty()).count()
+        ));
+
+        // 設定ファイルの指定を優先し、無ければ ESLint の設定を使う
+        let (constraints, source) = if !ctx.config.dep_constraints.is_empty() {
+            (&ctx.config.dep_constraints, "設定ファイル")
+        } else {
+            (&workspace.dep_constraints, ".eslintrc.json")
+        };
+        if constraints.is_empty() {
+            result
+                .details
+                .push("タグの依存制約 (depConstraints) が定義されていません".to_string());
+        } else {
+            result.details.push(format!(
+                "タグの依存制約: {} 件 ({})",
+                constraints.len(),
+                source
+            ));
+        }
+
+        // 他のプロジェクトへの import も見るため、ワークスペース全体の import グラフを使う
+        let imports = ctx.index.workspace_imports();
+        let project_of: Vec<Option<usize>> = imports
+            .files
+            .iter()
+            .map(|path| workspace.project_of(path))
+            .collect();
+
+        let mut graph = Graph::new("projects", "Nx プロジェクト間の依存関係");
+        for project in &projects {
+            graph.add_node(&project.name, &project.name, node_kind(project));
+        }
+
+        for (from, edges) in imports.edges.iter().enumerate() {
+            let Some(source) =
+                project_of[from].filter(|_| ctx.index.contains(&imports.files[from]))
+            else {
+                continue;
+            };
+            for edge in edges {
+                let Some(target) = project_of[edge.to].filter(|t| *t != source) else {
+                    continue;
+                };
+                let (source, target) = (&workspace.projects[source], &workspace.projects[target]);
+                graph.add_node(&target.name, &target.name, node_kind(target));
+                graph.add_edge(&source.name, &target.name, "");
+
+                // @nx/enforce-module-boundaries と同じ順で判定する
+                let applicable: Vec<_> = constraints
+                    .iter()
+                    .filter(|c| c.applies_to(&source.tags))
+                    .collect();
+                let reason = if target.project_type == "application" {
+                    Some("アプリケーションを import することはできません".to_string())
+                } else if constraints.is_empty() {
+                    None
+                } else if applicable.is_empty() {
+                    Some(
+                        "依存制約に一致するタグの無いプロジェクトは他のプロジェクトに依存できません"
+                            .to_string(),
+                    )
+                } else {
+                    applicable.iter().find_map(|c| c.violation(&target.tags))
+                };
+                let Some(reason) = reason else {
+                    continue;
+                };
+
+                let path = &imports.files[from];
+                let finding = ENFORCE_MODULE_BOUNDARIES.finding(format!(
+                    "{} → {} ('{}'): {}",
+                    source.name, target.name, edge.specifier, reason
+                ));
+                result.push_at(&ctx.index, path, edge.range.start, edge.range.end, finding);
+            }
+        }
+
+        let mut table = Table::new(
+            "Nx プロジェクト一覧",
+            &["プロジェクト", "種別", "ルート", "タグ", "依存先"],
+        );
+        for project in projects {
+            let dependencies: Vec<&str> = graph
+                .edges_from(&project.name)
+                .map(|e| e.to.as_str())
+                .collect();
+            table.push_row(vec![
+                project.name.clone(),
+                project.project_type.clone(),
+                ctx.index.relative(&project.root).to_string(),
+                project.tags.join(", "),
+                dependencies.join(", "),
+            ]);
+        }
+        result.tables.push(table);
+        result.graphs.push(graph);
+        Ok(result)
+    }
+}
+
+fn node_kind(project: &Project) -> &'static str {
+    if project.project_type == "application" {
+        "application"
+    } else {
+        "library"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, DEFAULT_FILE};
+    use crate::testing::TempProject;
+
+    fn project_json(name: &str, project_type: &str, tags: &str) -> String {
+        format!(
+            r#"{{ "name": "{}", "projectType": "{}", "sourceRoot": "{}/src", "tags": [{}] }}"#,
+            name,
+            project_type,
+            if project_type == "application" {
+                format!("apps/{}", name)
+            } else {
+                format!("libs/{}", name)
+            },
+            tags
+        )
+    }
+
+    fn analyze(extra: &[(&str, &str)]) -> AnalysisResult {
+        let projects = [
+            (
+                "apps/shop/project.json",
+                project_json("shop", "application", r#""type:app", "scope:shop""#),
+            ),
+            (
+                "libs/cart/project.json",
+                project_json("cart", "library", r#""type:feature", "scope:shop""#),
+            ),
+            (
+                "libs/ui/project.json",
+                project_json("ui", "library", r#""type:ui", "scope:admin""#),
+            ),
+            (
+                "libs/legacy/project.json",
+                project_json("legacy", "library", ""),
+            ),
+        ];
+        let mut files: Vec<(&str, &str)> = projects.iter().map(|(p, c)| (*p, c.as_str())).collect();
+        files.extend_from_slice(&[
+            ("nx.json", "{}"),
+            (
+                "apps/shop/src/main.ts",
+                "import { cart } from '../../../libs/cart/src/index';\n",
+            ),
+            (
+                "libs/cart/src/index.ts",
+                "import { button } from '../../ui/src/index';\nexport const cart = 1;\n",
+            ),
+            (
+                "libs/ui/src/index.ts",
+                "import { cart } from '../../cart/src/index';\nexport const button = 1;\n",
+            ),
+            (
+                "libs/legacy/src/index.ts",
+                "import { button } from '../../ui/src/index';\nimport '../../../apps/shop/src/main';\n",
+            ),
+        ]);
+        files.extend_from_slice(extra);
+        let project = TempProject::new(&files);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Config::discover(&project.root).unwrap(),
+        };
+        NxAnalyzer.analyze(&ctx).unwrap()
+    }
+
+    fn violations(result: &AnalysisResult) -> Vec<&str> {
+        result.findings.iter().map(|f| f.message.as_str()).collect()
+    }
+
+    const ESLINT: &str = r#"{
+  "overrides": [{
+    "files": ["*.ts"],
+    "rules": {
+      "@nx/enforce-module-boundaries": ["error", {
+        "depConstraints": [
+          { "sourceTag": "type:app", "onlyDependOnLibsWithTags": ["type:feature", "type:ui"] },
+          { "sourceTag": "type:ui", "onlyDependOnLibsWithTags": ["type:ui"] },
+          { "sourceTag": "scope:shop", "notDependOnLibsWithTags": ["scope:admin"] },
+          { "sourceTag": "type:*", "onlyDependOnLibsWithTags": ["*"] }
+        ]
+      }]
+    }
+  }]
+}"#;
+
+    #[test]
+    fn dep_constraints_from_eslint() {
+        let result = analyze(&[(".eslintrc.json", ESLINT)]);
+        assert_eq!(
+            violations(&result),
+            [
+                "cart → ui ('../../ui/src/index'): タグ scope:shop のプロジェクトは scope:admin のタグを持つプロジェクトに依存できません",
+                "legacy → ui ('../../ui/src/index'): 依存制約に一致するタグの無いプロジェクトは他のプロジェクトに依存できません",
+                "legacy → shop ('../../../apps/shop/src/main'): アプリケーションを import することはできません",
+                "ui → cart ('../../cart/src/index'): タグ type:ui のプロジェクトは type:ui のタグを持つプロジェクトにのみ依存できます",
+            ]
+        );
+        assert!(
+            result
+                .details
+                .contains(&"タグの依存制約: 4 件 (.eslintrc.json)".to_string())
+        );
+        assert!(
+            result
+                .details
+                .contains(&"タグの無いプロジェクト数: 1".to_string())
+        );
+        let location = result.findings[0].location.as_ref().unwrap();
+        assert!(location.file.ends_with("libs/cart/src/index.ts"));
+        assert_eq!(location.span.start_line, 1);
+    }
+
+    #[test]
+    fn config_constraints_take_precedence() {
+        let result = analyze(&[
+            (".eslintrc.json", ESLINT),
+            (
+                DEFAULT_FILE,
+                r#"{ "depConstraints": [{ "sourceTag": "*", "onlyDependOnLibsWithTags": ["*"] }] }"#,
+            ),
+        ]);
+        // アプリケーションへの import だけは制約によらず報告する
+        assert_eq!(
+            violations(&result),
+            [
+                "legacy → shop ('../../../apps/shop/src/main'): アプリケーションを import することはできません"
+            ]
+        );
+        assert!(
+            result
+                .details
+                .contains(&"タグの依存制約: 1 件 (設定ファイル)".to_string())
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::index::FileKind;
+use crate::routes;
+use crate::table::Table;
+
+const CATEGORY: &str = "パフォーマンス";
+
+const NO_LAZY_LOADING: Rule = Rule {
+    id: "performance/no-lazy-loading",
+    severity: Severity::Warning,
+    description: "loadComponent / loadChildren による遅延読み込みが見つからない",
+};
+
+const NGFOR_WITHOUT_TRACK_BY: Rule = Rule {
+    id: "performance/ngfor-without-trackby",
+    severity: Severity::Warning,
+    description: "*ngFor に trackBy が指定されていない",
+};
+
+const ON_PUSH_COVERAGE: Rule = Rule {
+    id: "performance/on-push-coverage",
+    severity: Severity::Warning,
+    description: "OnPush のコンポーネントの割合が設定ファイルの minOnPushCoverage に届かない",
+};
+
+const RULES: &[Rule] = &[NO_LAZY_LOADING, NGFOR_WITHOUT_TRACK_BY, ON_PUSH_COVERAGE];
+
+// パフォーマンス解析
+pub struct PerformanceAnalyzer;
+
+impl Analyzer for PerformanceAnalyzer {
+    fn id(&self) -> &str {
+        "performance"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        // loadComponent / loadChildren のどちらでも遅延読み込みとみなす
+        let mut lazy_loading = false;
+        routes::walk(ctx.index.routes(), &mut |route, _| {
+            lazy_loading |= route.is_lazy();
+        });
+        let mut virtual_scrolling = false;
+        let mut service_worker = false;
+
+        for file in ctx.index.files(FileKind::Ts) {
+            if let Some(module) = file.module() {
+                // インラインテンプレート中の cdk-virtual-scroll-viewport も対象
+                if module.any_name(|n| n.contains("VirtualScroll"))
+                    || module
+                        .strings
+                        .iter()
+                        .any(|s| s.value.contains("cdk-virtual-scroll"))
+                {
+                    virtual_scrolling = true;
+                }
+
+                if module.any_name(|n| n.contains("ServiceWorker")) {
+                    service_worker = true;
+                }
+            }
+        }
+
+        // テンプレートの *ngFor と @defer
+        let mut defer_blocks = 0;
+        for component in ctx.index.templates() {
+            let Some(template) = &component.template else {
+                continue;
+            };
+            defer_blocks += template
+                .blocks()
+                .iter()
+                .filter(|block| block.name == "defer")
+                .count();
+
+            let Some(content) = ctx.index.get(&component.file).and_then(|f| f.content()) else {
+                continue;
+            };
+            for element in template.elements() {
+                if let Some(directive) = element.structural_directive("ngFor")
+                    && !directive
+                        .value
+                        .as_deref()
+                        .is_some_and(|v| v.contains("trackBy"))
+                {
+                    result.push(
+                        NGFOR_WITHOUT_TRACK_BY
+                            .finding(format!(
+                                "{} のテンプレートで trackBy の無い *ngFor が使われています",
+                                component.component
+                            ))
+                            .at(
+                                &component.file,
+                                content,
+                                directive.range.start,
+                                directive.range.end,
+                            ),
+                    );
+                }
+            }
+        }
+
+        result.details.push(format!(
+            "遅延読み込み: {}",
+            if lazy_loading {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        // コンポーネントごとの変更検知戦略と OnPush の割合
+        let components = ctx.index.components();
+        let on_push = components.iter().filter(|c| c.is_on_push()).count();
+        let total = components.len();
+        result.details.push(format!(
+            "OnPush のコンポーネント: {} / {} ({})",
+            on_push,
+            total,
+            coverage_label(on_push, total)
+        ));
+        // 割合は切り捨てずに比べる (on_push / total < minimum / 100)
+        let below = |minimum: Option<u32>| {
+            minimum.filter(|m| total > 0 && on_push * 100 < *m as usize * total)
+        };
+        let (minimum, severity) = match below(ctx.config.min_on_push_coverage_error) {
+            Some(minimum) => (Some(minimum), Severity::Error),
+            None => (
+                below(ctx.config.min_on_push_coverage),
+                ON_PUSH_COVERAGE.severity,
+            ),
+        };
+        if let Some(minimum) = minimum {
+            let mut finding = ON_PUSH_COVERAGE.finding(format!(
+                "OnPush のコンポーネントの割合 {} が下限 {}% を下回っています (Default: {} 件)",
+                coverage_label(on_push, total),
+                minimum,
+                total - on_push
+            ));
+            finding.severity = severity;
+            result.push(finding);
+        }
+        let mut table = Table::new(
+            "コンポーネントの変更検知戦略",
+            &["コンポーネント", "ファイル", "変更検知"],
+        );
+        for component in components {
+            table.push_row(vec![
+                component.name.clone(),
+                ctx.index.relative(&component.file).to_string(),
+                component
+                    .change_detection
+                    .clone()
+                    .unwrap_or_else(|| "Default".to_string()),
+            ]);
+        }
+        result.tables.push(table);
+        result.details.push(format!(
+            "仮想スクロール: {}",
+            if virtual_scrolling {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result
+            .details
+            .push(format!("@defer ブロック数: {}", defer_blocks));
+        result.details.push(format!(
+            "サービスワーカー: {}",
+            if service_worker {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+
+        if !lazy_loading {
+            result.push(NO_LAZY_LOADING.finding("遅延読み込みが確認できません"));
+        }
+
+        Ok(result)
+    }
+}
+
+// 小数第 1 位までの割合 (第 2 位以下は切り捨て、対象が無い場合は "-")
+fn coverage_label(part: usize, total: usize) -> String {
+    if total == 0 {
+        return "-".to_string();
+    }
+    let permille = part * 1000 / total;
+    format!("{}.{}%", permille / 10, permille % 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::testing::TempProject;
+
+    fn lazy_findings(files: &[(&str, &str)]) -> usize {
+        let project = TempProject::new(files);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Default::default(),
+        };
+        let result = PerformanceAnalyzer.analyze(&ctx).unwrap();
+        result
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == NO_LAZY_LOADING.id)
+            .count()
+    }
+
+    #[test]
+    fn load_component_counts_as_lazy_loading() {
+        assert_eq!(
+            lazy_findings(&[(
+                "src/main.ts",
+                "provideRouter([{ path: 'a', loadComponent: () => import('./a.component').then(m => m.A) }]);\n",
+            )]),
+            0
+        );
+        // ルート定義の外の loadChildren という名前は数えない
+        assert_eq!(
+            lazy_findings(&[(
+                "src/main.ts",
+                "const loadChildren = 1;\nprovideRouter([{ path: 'a', component: A }]);\n",
+            )]),
+            1
+        );
+    }
+
+    #[test]
+    fn on_push_coverage_thresholds() {
+        // 10 件中 7 件が OnPush (70%)
+        let mut files: Vec<(String, String)> = (0..10)
+            .map(|i| {
+                let strategy = if i < 7 {
+                    ", changeDetection: ChangeDetectionStrategy.OnPush"
+                } else {
+                    ""
+                };
+                (
+                    format!("src/c{}.component.ts", i),
+                    format!(
+                        "@Component({{ selector: 'c{}'{} }})\nexport class C{} {{}}\n",
+                        i, strategy, i
+                    ),
+                )
+            })
+            .collect();
+        files.push((
+            "src/main.ts".to_string(),
+            "provideRouter([{ path: 'a', loadComponent: () => import('./c0.component') }]);\n"
+                .to_string(),
+        ));
+        let files: Vec<(&str, &str)> = files
+            .iter()
+            .map(|(p, c)| (p.as_str(), c.as_str()))
+            .collect();
+        let project = TempProject::new(&files);
+        let analyze = |warning, error| {
+            let ctx = ProjectContext {
+                project_path: project.root.clone(),
+                index: project.index(),
+                config: Config {
+                    min_on_push_coverage: warning,
+                    min_on_push_coverage_error: error,
+                    ..Default::default()
+                },
+            };
+            PerformanceAnalyzer.analyze(&ctx).unwrap()
+        };
+
+        let result = analyze(None, None);
+        assert!(result.findings.is_empty());
+        assert!(
+            result
+                .details
+                .contains(&"OnPush のコンポーネント: 7 / 10 (70.0%)".to_string())
+        );
+        // ちょうど下限なら報告しない
+        assert!(analyze(Some(70), None).findings.is_empty());
+        let result = analyze(Some(71), Some(50));
+        assert_eq!(result.findings[0].severity, Severity::Warning);
+        assert_eq!(
+            result.findings[0].message,
+            "OnPush のコンポーネントの割合 70.0% が下限 71% を下回っています (Default: 3 件)"
+        );
+        let result = analyze(Some(80), Some(71));
+        assert_eq!(result.findings.len(), 1);
+        assert_eq!(result.findings[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn coverage_is_not_rounded_up() {
+        assert_eq!(coverage_label(799, 1000), "79.9%");
+        assert_eq!(coverage_label(2, 3), "66.6%");
+        assert_eq!(coverage_label(0, 0), "-");
+    }
+}
@@ -0,0 +1,754 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::graph::Graph;
+use crate::index::{FileKind, ProjectIndex};
+use crate::routes::{self, Route};
+use crate::table::Table;
+use crate::ts::Module;
+
+const CATEGORY: &str = "ルーティング・ナビゲーション";
+
+const NO_ROUTING: Rule = Rule {
+    id: "routing/no-routing",
+    severity: Severity::Warning,
+    description: "ルーティング設定が見つからない",
+};
+
+const DUPLICATE_PATH: Rule = Rule {
+    id: "routing/duplicate-path",
+    severity: Severity::Warning,
+    description: "同じ URL に一致するルートの重複 (後のルートは表示されない) と、同じパスに子ルートを分けて定義した親ルート",
+};
+
+const MISSING_WILDCARD: Rule = Rule {
+    id: "routing/missing-wildcard",
+    severity: Severity::Warning,
+    description: "未定義の URL を受けるワイルドカードルート (**) が無い",
+};
+
+const MISSING_REQUIRED_GUARD: Rule = Rule {
+    id: "routing/missing-required-guard",
+    severity: Severity::Error,
+    description: "設定ファイルの guardRules で必須にしたガードが無いルート",
+};
+
+const UNGUARDED_ROUTE: Rule = Rule {
+    id: "routing/unguarded-route",
+    severity: Severity::Info,
+    description: "ガード (自身または祖先の canActivate / canActivateChild / canMatch) の無いルートがある (件数をまとめて 1 件報告)",
+};
+
+const RULES: &[Rule] = &[
+    NO_ROUTING,
+    DUPLICATE_PATH,
+    MISSING_WILDCARD,
+    MISSING_REQUIRED_GUARD,
+    UNGUARDED_ROUTE,
+];
+
+// 詳細に書き出すガードの無いルートのパスの上限
+const UNGUARDED_EXAMPLES: usize = 10;
+
+// クラスで実装するガードのインターフェース
+const GUARD_INTERFACES: &[&str] = &[
+    "CanActivate",
+    "CanActivateChild",
+    "CanMatch",
+    "CanLoad",
+    "CanDeactivate",
+];
+
+// 関数で実装するガードの型
+const GUARD_FUNCTION_TYPES: &[&str] = &[
+    "CanActivateFn",
+    "CanActivateChildFn",
+    "CanMatchFn",
+    "CanDeactivateFn",
+];
+
+// プロジェクト内で定義されたガード
+struct GuardDefinition {
+    name: String,
+    class: bool,
+    // 実装するインターフェースまたは関数の型
+    types: Vec<String>,
+    file: String,
+}
+
+// ルーティング解析
+pub struct RoutingAnalyzer;
+
+impl Analyzer for RoutingAnalyzer {
+    fn id(&self) -> &str {
+        "routing"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        let mut routing_module_found = false;
+        let mut guards = Vec::new();
+
+        for file in ctx.index.files(FileKind::Ts) {
+            if let Some(module) = file.module() {
+                if module.has_name("RouterModule")
+                    || module.has_name("Routes")
+                    || module.has_name("provideRouter")
+                {
+                    routing_module_found = true;
+                }
+
+                guards.extend(guard_definitions(&file.path, module));
+            }
+        }
+
+        result.details.push(format!(
+            "ルーティング設定: {}",
+            if routing_module_found {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        let classes = guards.iter().filter(|g| g.class).count();
+        result.details.push(if guards.is_empty() {
+            "ガード機能: 未確認".to_string()
+        } else {
+            format!(
+                "ガード機能: 実装済み (クラス {} / 関数 {})",
+                classes,
+                guards.len() - classes
+            )
+        });
+
+        if !routing_module_found {
+            result.push(NO_ROUTING.finding("ルーティング設定が確認できません"));
+        }
+
+        let tree = ctx.index.routes();
+        push_guard_table(&mut result, &ctx.index, tree, &guards);
+        if tree.is_empty() {
+            result.details.push("遅延読み込み: 未確認".to_string());
+            return Ok(result);
+        }
+
+        let (mut eager, mut lazy, mut redirects) = (0, 0, 0);
+        let mut unguarded = Vec::new();
+        let mut wildcard = false;
+        let mut table = Table::new(
+            "ルートツリー",
+            &[
+                "ルート",
+                "完全なパス",
+                "読み込み",
+                "読み込み先",
+                "ガード",
+                "リゾルバー",
+                "定義ファイル",
+            ],
+        );
+        routes::walk(tree, &mut |route, depth| {
+            if route.is_redirect() {
+                redirects += 1;
+            } else if route.is_lazy() {
+                lazy += 1;
+            } else {
+                eager += 1;
+            }
+            if route.is_wildcard() {
+                wildcard = true;
+            }
+            if is_unguarded(route) {
+                unguarded.push(route.path_with_outlet());
+            }
+
+            let indent = if depth == 0 {
+                String::new()
+            } else {
+                format!("{}└ ", "  ".repeat(depth - 1))
+            };
+            let target = route
+                .component
+                .clone()
+                .or_else(|| {
+                    route
+                        .load_component
+                        .as_deref()
+                        .map(|p| ctx.index.relative(p).to_string())
+                })
+                .or_else(|| {
+                    route
+                        .load_children
+                        .as_deref()
+                        .map(|p| ctx.index.relative(p).to_string())
+                })
+                .unwrap_or_default();
+            table.push_row(vec![
+                format!("{}{}", indent, display_path(&route.path)),
+                route.path_with_outlet(),
+                load_kind(route),
+                target,
+                route.effective_guards.join(", "),
+                route.resolvers.join(", "),
+                ctx.index.relative(&route.file).to_string(),
+            ]);
+        });
+
+        result.details.push(format!(
+            "ルート数: {} (即時読み込み {} / 遅延読み込み {} / リダイレクト {})",
+            eager + lazy + redirects,
+            eager,
+            lazy,
+            redirects
+        ));
+        result.details.push(format!(
+            "遅延読み込み: {}",
+            if lazy > 0 {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "ワイルドカードルート (**): {}",
+            if wildcard { "あり" } else { "なし" }
+        ));
+        // 公開ページの多いアプリケーションでは件数が多くなるため、ルートごとには指摘しない
+        let count = unguarded.len();
+        // 同じパスのルートが複数あっても詳細にはパスを 1 回だけ書く
+        let mut seen_paths = HashSet::new();
+        unguarded.retain(|path| seen_paths.insert(path.clone()));
+        result.details.push(if unguarded.is_empty() {
+            "ガードの無いルート: 0".to_string()
+        } else if unguarded.len() > UNGUARDED_EXAMPLES {
+            format!(
+                "ガードの無いルート: {} ({} など)",
+                count,
+                unguarded[..UNGUARDED_EXAMPLES].join(", ")
+            )
+        } else {
+            format!("ガードの無いルート: {} ({})", count, unguarded.join(", "))
+        });
+        result.tables.push(table);
+        if !unguarded.is_empty() {
+            result.push(UNGUARDED_ROUTE.finding(format!(
+                "ガードの無いルートが {} 件あります (必須のガードは guardRules で指定できます)",
+                count
+            )));
+        }
+
+        if !wildcard {
+            result.push(MISSING_WILDCARD.finding(
+                "ワイルドカードルート (path: '**') がありません (未定義の URL でエラーになります)",
+            ));
+        }
+        check_duplicates(&mut result, &ctx.index, tree);
+        check_guard_rules(&mut result, ctx, tree);
+        result.graphs.push(to_graph(tree));
+
+        Ok(result)
+    }
+}
+
+// クラス (インターフェースの実装か、ガードのメソッドを持つ @Injectable) と
+// 関数 (CanActivateFn 等の型の変数か、それを返すファクトリー関数) のガード
+fn guard_definitions(path: &str, module: &Module) -> Vec<GuardDefinition> {
+    let base = |t: &str| t.split('<').next().unwrap_or(t).trim().to_string();
+    let mut guards = Vec::new();
+    for class in &module.classes {
+        let mut types: Vec<String> = class
+            .implements
+            .iter()
+            .map(|i| base(i))
+            .filter(|i| GUARD_INTERFACES.contains(&i.as_str()))
+            .collect();
+        if types.is_empty() && class.decorator("Injectable").is_some() {
+            // implements を省略したガード (canActivate() を持つサービス)
+            types = GUARD_INTERFACES
+                .iter()
+                .filter(|i| class.member(&lower_first(i)).is_some())
+                .map(|i| i.to_string())
+                .collect();
+        }
+        if !types.is_empty() {
+            guards.push(GuardDefinition {
+                name: class.name.clone(),
+                class: true,
+                types,
+                file: path.to_string(),
+            });
+        }
+    }
+
+    let functions = module
+        .variables
+        .iter()
+        .map(|v| (&v.name, &v.type_annotation))
+        .chain(module.functions.iter().map(|f| (&f.name, &f.return_type)));
+    for (name, annotation) in functions {
+        let Some(annotation) = annotation.as_deref().map(base) else {
+            continue;
+        };
+        if GUARD_FUNCTION_TYPES.contains(&annotation.as_str()) {
+            guards.push(GuardDefinition {
+                name: name.clone(),
+                class: false,
+                types: vec![annotation],
+                file: path.to_string(),
+            });
+        }
+    }
+    guards
+}
+
+fn lower_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|c| c.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+// 定義されたガードと、ルートで使われているが定義の見つからないガード (ライブラリ等) の一覧
+fn push_guard_table(
+    result: &mut AnalysisResult,
+    index: &ProjectIndex,
+    tree: &[Route],
+    guards: &[GuardDefinition],
+) {
+    let mut used: BTreeMap<&str, usize> = BTreeMap::new();
+    routes::walk(tree, &mut |route, _| {
+        let mut names: Vec<&str> = route
+            .effective_guards
+            .iter()
+            .chain(&route.can_activate_child)
+            .chain(&route.can_deactivate)
+            .map(String::as_str)
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        for name in names {
+            // canActivateChild だけを持つ親ルート自体は保護されない
+            let protects = !route.is_redirect()
+                && (route.effective_guards.iter().any(|g| g == name)
+                    || route.can_deactivate.iter().any(|g| g == name));
+            *used.entry(name).or_default() += usize::from(protects);
+        }
+    });
+    if guards.is_empty() && used.is_empty() {
+        return;
+    }
+
+    let mut table = Table::new(
+        "ガード一覧",
+        &["ガード", "種類", "定義ファイル", "保護するルート数"],
+    );
+    for guard in guards {
+        table.push_row(vec![
+            guard.name.clone(),
+            format!(
+                "{} ({})",
+                if guard.class { "クラス" } else { "関数" },
+                guard.types.join(", ")
+            ),
+            index.relative(&guard.file).to_string(),
+            used.get(guard.name.as_str())
+                .copied()
+                .unwrap_or(0)
+                .to_string(),
+        ]);
+    }
+    for (name, count) in &used {
+        if !guards.iter().any(|g| g.name == *name) {
+            table.push_row(vec![
+                name.to_string(),
+                if *name == routes::INLINE_FUNCTION {
+                    "関数 (ルート定義内)"
+                } else {
+                    "不明 (定義が見つかりません)"
+                }
+                .to_string(),
+                String::new(),
+                count.to_string(),
+            ]);
+        }
+    }
+    result.tables.push(table);
+}
+
+// guardRules の path 以下のルート (リダイレクトを除く) に必須のガードがそろっているか
+fn check_guard_rules(result: &mut AnalysisResult, ctx: &ProjectContext, tree: &[Route]) {
+    let rules = &ctx.config.guard_rules;
+    if rules.is_empty() {
+        return;
+    }
+    let mut violations = 0;
+    for rule in rules {
+        let mut matched = 0;
+        routes::walk(tree, &mut |route, _| {
+            if route.is_redirect() || !rule.applies_to(&route.full_path) {
+                return;
+            }
+            matched += 1;
+            let missing: Vec<&str> = rule
+                .guards
+                .iter()
+                .filter(|g| !route.effective_guards.contains(g))
+                .map(String::as_str)
+                .collect();
+            if missing.is_empty() {
+                return;
+            }
+            violations += 1;
+            let finding = MISSING_REQUIRED_GUARD.finding(format!(
+                "ルート {} に必須のガード {} がありません (guardRules: {})",
+                route.full_path,
+                missing.join(", "),
+                rule.path
+            ));
+            result.push_at(
+                &ctx.index,
+                &route.file,
+                route.range.start,
+                route.range.start,
+                finding,
+            );
+        });
+        if matched == 0 {
+            result.details.push(format!(
+                "guardRules の {} に一致するルートがありません",
+                rule.path
+            ));
+        }
+    }
+    result.details.push(format!(
+        "必須ガードの規則: {} 件 (違反しているルート {})",
+        rules.len(),
+        violations
+    ));
+}
+
+// 完全なパスと表示先の outlet が同じルート
+// 末端どうしと、子ルートを持つ親どうしで比べる (祖先と、path が空の子孫は別のルート)
+fn check_duplicates(result: &mut AnalysisResult, index: &ProjectIndex, tree: &[Route]) {
+    let mut seen: HashMap<(&str, Option<&str>, bool), &Route> = HashMap::new();
+    let mut ancestors: Vec<&Route> = Vec::new();
+    routes::walk(tree, &mut |route, depth| {
+        ancestors.truncate(depth);
+        ancestors.push(route);
+        if route.is_redirect() {
+            return;
+        }
+        let parent = !route.children.is_empty();
+        let key = (route.full_path.as_str(), route.outlet.as_deref(), parent);
+        let Some(first) = seen.get(&key) else {
+            seen.insert(key, route);
+            return;
+        };
+        if ancestors.iter().any(|a| std::ptr::eq(*a, *first)) {
+            return;
+        }
+        let finding = DUPLICATE_PATH.finding(if parent {
+            format!(
+                "子ルートを持つルート {} が重複しています (先に {} で定義されています。子ルートを 1 つの定義にまとめてください)",
+                route.path_with_outlet(),
+                index.relative(&first.file)
+            )
+        } else {
+            format!(
+                "ルート {} が重複しています (先に {} で定義されています)",
+                route.path_with_outlet(),
+                index.relative(&first.file)
+            )
+        });
+        result.push_at(
+            index,
+            &route.file,
+            route.range.start,
+            route.range.start,
+            finding,
+        );
+    });
+}
+
+// 画面を表示する (または読み込む) ルートで、遷移時に実行されるガードが無いもの
+// リダイレクト・ワイルドカード・children をまとめるだけのルートは除く
+fn is_unguarded(route: &Route) -> bool {
+    let shows = route.component.is_some() || route.is_lazy();
+    shows && !route.is_redirect() && !route.is_wildcard() && route.effective_guards.is_empty()
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() { "(空)" } else { path }
+}
+
+fn load_kind(route: &Route) -> String {
+    if let Some(target) = &route.redirect_to {
+        format!("リダイレクト → '{}'", target)
+    } else if route.load_component.is_some() {
+        "loadComponent".to_string()
+    } else if route.load_children.is_some() {
+        "loadChildren".to_string()
+    } else if route.component.is_some() {
+        "即時".to_string()
+    } else {
+        "-".to_string()
+    }
+}
+
+// ルーターを根とするツリー。遅延読み込みのルートは破線で、loadChildren 先の子は辺にラベルを付ける
+fn to_graph(tree: &[Route]) -> Graph {
+    fn add(graph: &mut Graph, routes: &[Route], parent: &str, label: &str, next: &mut usize) {
+        for route in routes {
+            let id = format!("route{}", next);
+            *next += 1;
+            let (text, kind) = match (&route.redirect_to, &route.component) {
+                (Some(target), _) => (
+                    format!("{} → '{}'", route.path_with_outlet(), target),
+                    "redirect",
+                ),
+                (None, _) if route.is_lazy() => (route.path_with_outlet(), "lazy"),
+                (None, Some(component)) => (
+                    format!("{} ({})", route.path_with_outlet(), component),
+                    "route",
+                ),
+                (None, None) => (route.path_with_outlet(), "route"),
+            };
+            graph.add_node(&id, &text, kind);
+            graph.add_edge(parent, &id, label);
+            let child_label = if route.load_children.is_some() {
+                "loadChildren"
+            } else {
+                ""
+            };
+            add(graph, &route.children, &id, child_label, next);
+        }
+    }
+
+    let mut graph = Graph::new("routes", "ルートツリー");
+    graph.add_node("router", "Router", "application");
+    add(&mut graph, tree, "router", "", &mut 0);
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, DEFAULT_FILE};
+    use crate::testing::TempProject;
+
+    // files に設定ファイルがあれば読み込む
+    fn analyze(files: &[(&str, &str)]) -> AnalysisResult {
+        let project = TempProject::new(files);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Config::discover(&project.root).unwrap(),
+        };
+        RoutingAnalyzer.analyze(&ctx).unwrap()
+    }
+
+    fn messages<'a>(result: &'a AnalysisResult, rule: &Rule) -> Vec<&'a str> {
+        result
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == rule.id)
+            .map(|f| f.message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn duplicates_respect_outlets() {
+        let result = analyze(&[(
+            "src/app.routes.ts",
+            r#"provideRouter([
+  { path: 'chat', component: ChatComponent },
+  { path: 'chat', component: ChatPanelComponent, outlet: 'side' },
+  { path: 'chat', component: OtherChatComponent, outlet: 'primary' },
+  { path: 'help', component: HelpComponent, outlet: 'side' },
+  { path: 'help', component: HelpPanelComponent, outlet: 'side' },
+  { path: '**', component: NotFoundComponent },
+]);
+"#,
+        )]);
+        assert_eq!(
+            messages(&result, &DUPLICATE_PATH),
+            [
+                "ルート /chat が重複しています (先に src/app.routes.ts で定義されています)",
+                "ルート /help (outlet: side) が重複しています (先に src/app.routes.ts で定義されています)",
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_parents() {
+        let result = analyze(&[(
+            "src/app.routes.ts",
+            r#"provideRouter([
+  { path: 'admin', component: AdminComponent, children: [
+    { path: '', component: DashboardComponent, children: [
+      { path: 'stats', component: StatsComponent },
+    ] },
+    { path: 'users', component: UsersComponent },
+  ] },
+  { path: 'admin', children: [
+    { path: 'logs', component: LogsComponent },
+  ] },
+  { path: 'settings', component: SettingsComponent },
+  { path: 'settings', children: [
+    { path: 'profile', component: ProfileComponent },
+  ] },
+  { path: '**', component: NotFoundComponent },
+]);
+"#,
+        )]);
+        // 親と path が空の子孫、末端と親は同じパスでも重複としない
+        assert_eq!(
+            messages(&result, &DUPLICATE_PATH),
+            [
+                "子ルートを持つルート /admin が重複しています (先に src/app.routes.ts で定義されています。子ルートを 1 つの定義にまとめてください)"
+            ]
+        );
+    }
+
+    #[test]
+    fn unguarded_routes() {
+        let result = analyze(&[(
+            "src/app.routes.ts",
+            r#"provideRouter([
+  { path: '', component: HomeComponent },
+  { path: 'admin', canActivateChild: [adminGuard], children: [
+    { path: 'users', component: UsersComponent },
+  ] },
+  { path: 'shop', children: [
+    { path: 'cart', loadComponent: () => import('./cart.component') },
+  ] },
+  { path: 'old', redirectTo: '' },
+  { path: '**', component: NotFoundComponent },
+]);
+"#,
+        )]);
+        assert_eq!(
+            messages(&result, &UNGUARDED_ROUTE),
+            ["ガードの無いルートが 2 件あります (必須のガードは guardRules で指定できます)"]
+        );
+        assert!(result.findings.iter().all(|f| f.location.is_none()));
+        assert!(
+            result
+                .details
+                .contains(&"ガードの無いルート: 2 (/, /shop/cart)".to_string())
+        );
+    }
+
+    #[test]
+    fn guard_rules() {
+        let result = analyze(&[
+            (
+                DEFAULT_FILE,
+                r#"{
+  "guardRules": [
+    { "path": "/admin", "guards": ["authGuard", "roleGuard"] },
+    { "path": "/account/", "guards": ["authGuard"] },
+    { "path": "/reports", "guards": ["authGuard"] }
+  ]
+}"#,
+            ),
+            (
+                "src/app.routes.ts",
+                r#"provideRouter([
+  { path: 'admin', canActivate: [authGuard, roleGuard('admin')], children: [
+    { path: 'users', component: UsersComponent },
+  ] },
+  { path: 'administration', component: AdministrationComponent },
+  { path: 'account', component: AccountComponent, children: [
+    { path: 'orders', canActivate: [authGuard], component: OrdersComponent },
+  ] },
+  { path: 'account/old', redirectTo: '/account' },
+  { path: '**', component: NotFoundComponent },
+]);
+"#,
+            ),
+        ]);
+        // /administration は /admin に一致せず、リダイレクトは対象外
+        assert_eq!(
+            messages(&result, &MISSING_REQUIRED_GUARD),
+            ["ルート /account に必須のガード authGuard がありません (guardRules: /account/)"]
+        );
+        assert!(
+            result
+                .details
+                .contains(&"guardRules の /reports に一致するルートがありません".to_string())
+        );
+        assert!(
+            result
+                .details
+                .contains(&"必須ガードの規則: 3 件 (違反しているルート 1)".to_string())
+        );
+    }
+
+    #[test]
+    fn guard_definitions_table() {
+        let result = analyze(&[
+            (
+                "src/guards.ts",
+                r#"export const authGuard: CanActivateFn = () => true;
+export function roleGuard(role: string): CanMatchFn {
+  return () => true;
+}
+export class AdminGuard implements CanActivate<unknown> {}
+@Injectable()
+export class FeatureGuard {
+  canMatch() { return true; }
+}
+"#,
+            ),
+            (
+                "src/app.routes.ts",
+                r#"provideRouter([
+  { path: 'admin', canActivate: [authGuard], canActivateChild: [AdminGuard], children: [
+    { path: 'users', canMatch: [roleGuard('admin')], component: UsersComponent },
+  ] },
+  { path: 'beta', canActivate: [LegacyGuard, () => true], component: BetaComponent },
+]);
+"#,
+            ),
+        ]);
+        let table = result
+            .tables
+            .iter()
+            .find(|t| t.title == "ガード一覧")
+            .unwrap();
+        let rows: Vec<Vec<&str>> = table
+            .rows
+            .iter()
+            .map(|row| row.iter().map(String::as_str).collect())
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ["AdminGuard", "クラス (CanActivate)", "src/guards.ts", "1"],
+                ["FeatureGuard", "クラス (CanMatch)", "src/guards.ts", "0"],
+                ["authGuard", "関数 (CanActivateFn)", "src/guards.ts", "2"],
+                ["roleGuard", "関数 (CanMatchFn)", "src/guards.ts", "1"],
+                [routes::INLINE_FUNCTION, "関数 (ルート定義内)", "", "1"],
+                ["LegacyGuard", "不明 (定義が見つかりません)", "", "1"],
+            ]
+        );
+        assert!(
+            result
+                .details
+                .contains(&"ガード機能: 実装済み (クラス 2 / 関数 2)".to_string())
+        );
+    }
+}
@@ -0,0 +1,719 @@
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Finding, Rule, Severity};
+use crate::index::{FileKind, SourceFile};
+use crate::ts::{Call, Class, ExprKind, MemberKind, Module};
+
+const CATEGORY: &str = "RxJS";
+
+const SUBSCRIPTION_LEAK: Rule = Rule {
+    id: "rxjs/subscription-leak",
+    severity: Severity::Warning,
+    description: "コンポーネント・ディレクティブの破棄時に解除されない subscribe()",
+};
+
+const NESTED_SUBSCRIBE: Rule = Rule {
+    id: "rxjs/nested-subscribe",
+    severity: Severity::Warning,
+    description: "pipe() のオペレーター (map / tap など) のコールバック内での subscribe() (switchMap 等で合成できないか)",
+};
+
+const SUBSCRIBE_IN_SUBSCRIBE: Rule = Rule {
+    id: "rxjs/subscribe-in-subscribe",
+    severity: Severity::Warning,
+    description: "subscribe() のコールバック内での subscribe() (switchMap 等で合成できないか)",
+};
+
+const EFFECT_MISSING_CATCH_ERROR: Rule = Rule {
+    id: "rxjs/effect-missing-catch-error",
+    severity: Severity::Error,
+    description: "catchError の無い NgRx の createEffect (エラーで effect が停止する)",
+};
+
+const SHARE_REPLAY_WITHOUT_REF_COUNT: Rule = Rule {
+    id: "rxjs/share-replay-without-ref-count",
+    severity: Severity::Warning,
+    description: "refCount: true を指定しない shareReplay (購読者が居なくなっても元の購読が残る)",
+};
+
+const TO_PROMISE: Rule = Rule {
+    id: "rxjs/to-promise",
+    severity: Severity::Warning,
+    description: "非推奨の toPromise() (firstValueFrom / lastValueFrom へ置き換え)",
+};
+
+const PUBLIC_SUBJECT: Rule = Rule {
+    id: "rxjs/public-subject",
+    severity: Severity::Warning,
+    description: "サービスが公開している Subject (private にして asObservable() で公開する)",
+};
+
+const RULES: &[Rule] = &[
+    SUBSCRIPTION_LEAK,
+    NESTED_SUBSCRIBE,
+    SUBSCRIBE_IN_SUBSCRIBE,
+    EFFECT_MISSING_CATCH_ERROR,
+    SHARE_REPLAY_WITHOUT_REF_COUNT,
+    TO_PROMISE,
+    PUBLIC_SUBJECT,
+];
+
+// 購読をコンポーネントの破棄で終わらせるオペレーター
+// (take / first は通知が来なければ完了しないため数えない)
+const COMPLETING_OPERATORS: &[&str] = &["takeUntil", "takeUntilDestroyed"];
+
+// 完了する (または Angular が破棄時に解除する) Observable を返すサービスの型
+const SELF_COMPLETING_TYPES: &[&str] = &["HttpClient", "ActivatedRoute"];
+
+// 内側の Observable を購読するオペレーター (effect の中ではここからエラーが流れてくる)
+const FLATTENING_OPERATORS: &[&str] = &["switchMap", "mergeMap", "concatMap", "exhaustMap"];
+
+// 内側の Observable を購読するもののほか、元の Observable が完了しても購読を続けうるオペレーター
+// (これらを通す場合は SELF_COMPLETING_TYPES の Observable でも解除が必要)
+const EXTENDING_OPERATORS: &[&str] = &[
+    "expand",
+    "mergeScan",
+    "switchScan",
+    "repeat",
+    "repeatWhen",
+    "retry",
+    "retryWhen",
+    "mergeWith",
+    "concatWith",
+    "combineLatestWith",
+    "zipWith",
+    "raceWith",
+];
+
+const SUBJECTS: &[&str] = &[
+    "Subject",
+    "BehaviorSubject",
+    "ReplaySubject",
+    "AsyncSubject",
+];
+
+// Observable を返す rxjs の生成関数 (rxjs から import したものだけを見る)
+const CREATION_FUNCTIONS: &[&str] = &[
+    "of",
+    "from",
+    "defer",
+    "interval",
+    "timer",
+    "fromEvent",
+    "forkJoin",
+    "combineLatest",
+    "merge",
+    "concat",
+    "zip",
+    "race",
+];
+
+// Observable の購読の後始末と RxJS のアンチパターンを検査する
+pub struct RxjsAnalyzer;
+
+impl Analyzer for RxjsAnalyzer {
+    fn id(&self) -> &str {
+        "rxjs"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+        // ファイルごとの検査は並列に行い、ファイル順にまとめる
+        let scans: Vec<FileScan> = ctx
+            .index
+            .par_files(FileKind::Ts)
+            .map(|file| scan_file(ctx, file))
+            .collect();
+        let mut subscriptions = 0;
+        let mut leaks = 0;
+        let mut destroyed_files = 0;
+        // テンプレートの async パイプによる購読 (破棄時に Angular が解除する)
+        let mut async_pipes = 0;
+        let mut async_components = 0;
+        for scan in scans {
+            subscriptions += scan.subscriptions;
+            leaks += scan.leaks;
+            destroyed_files += usize::from(scan.destroyed);
+            async_pipes += scan.async_pipes;
+            async_components += scan.async_components;
+            result.findings.extend(scan.findings);
+        }
+
+        result.details.push(format!(
+            "コンポーネント・ディレクティブの購読数: {} (subscribe() {} / async パイプ {})",
+            subscriptions + async_pipes,
+            subscriptions,
+            async_pipes
+        ));
+        result.details.push(format!(
+            "破棄時に解除されない購読: {} (async パイプの購読は Angular が解除します)",
+            leaks
+        ));
+        result.details.push(format!(
+            "async パイプで購読しているコンポーネント数: {}",
+            async_components
+        ));
+        result.details.push(format!(
+            "takeUntilDestroyed / DestroyRef を使うファイル数: {}",
+            destroyed_files
+        ));
+        Ok(result)
+    }
+}
+
+// 1ファイル分の検査結果
+#[derive(Default)]
+struct FileScan {
+    subscriptions: usize,
+    leaks: usize,
+    destroyed: bool,
+    async_pipes: usize,
+    async_components: usize,
+    findings: Vec<Finding>,
+}
+
+fn scan_file(ctx: &ProjectContext, file: &SourceFile) -> FileScan {
+    let mut scan = FileScan::default();
+    let (Some(content), Some(module)) = (file.content(), file.module()) else {
+        return scan;
+    };
+    scan.destroyed = module.has_name("takeUntilDestroyed") || module.has_name("DestroyRef");
+    check_anti_patterns(&mut scan.findings, module, &file.path, content);
+    for class in &module.classes {
+        if class.decorator("Component").is_none() && class.decorator("Directive").is_none() {
+            continue;
+        }
+        let pipes = async_pipe_count(ctx, &file.path, &class.name);
+        scan.async_pipes += pipes;
+        scan.async_components += usize::from(pipes > 0);
+
+        let lifecycle = ClassLifecycle::new(module, class);
+        for call in module.method_calls("subscribe") {
+            if call.scope.class.as_deref() != Some(class.name.as_str()) {
+                continue;
+            }
+            scan.subscriptions += 1;
+            if lifecycle.is_released(module, call) {
+                continue;
+            }
+            scan.leaks += 1;
+            scan.findings.push(
+                SUBSCRIPTION_LEAK
+                    .finding(format!(
+                        "{}.{} の subscribe() が破棄時に解除されません (takeUntilDestroyed / takeUntil / ngOnDestroy での unsubscribe が見つかりません)",
+                        class.name,
+                        call.scope.member.as_deref().unwrap_or("?")
+                    ))
+                    .at(&file.path, content, call.range.start, call.range.end),
+            );
+        }
+    }
+    scan
+}
+
+// コンポーネントのテンプレートで async パイプを使っている箇所の数
+fn async_pipe_count(ctx: &ProjectContext, path: &str, class: &str) -> usize {
+    ctx.index
+        .templates()
+        .iter()
+        .filter(|t| t.component_file == path && t.component == class)
+        .filter_map(|t| t.template.as_ref())
+        .map(|t| t.pipes().iter().filter(|p| **p == "async").count())
+        .sum()
+}
+
+// 購読の後始末以外のアンチパターン (コンポーネントに限らずすべてのファイルが対象)
+fn check_anti_patterns(findings: &mut Vec<Finding>, module: &Module, path: &str, content: &str) {
+    for call in module.method_calls("subscribe") {
+        // 最も内側の subscribe() / pipe() の引数の中にあるか
+        let enclosing = call
+            .enclosing_calls
+            .iter()
+            .rev()
+            .map(|&i| module.calls[i].method())
+            .find(|method| matches!(*method, "subscribe" | "pipe"));
+        let finding = match enclosing {
+            Some("subscribe") => SUBSCRIBE_IN_SUBSCRIBE.finding(
+                "subscribe() の中で subscribe() しています (switchMap などで合成できます)",
+            ),
+            Some(_) => NESTED_SUBSCRIBE.finding(
+                "pipe() のオペレーターの中で subscribe() しています (switchMap などで合成できます)",
+            ),
+            None => continue,
+        };
+        findings.push(finding.at(path, content, call.range.start, call.range.end));
+    }
+
+    if module.imports_from("@ngrx/effects").next().is_some() {
+        for (i, call) in module.calls.iter().enumerate() {
+            if call.callee != "createEffect" {
+                continue;
+            }
+            let inner: Vec<&Call> = module
+                .calls
+                .iter()
+                .filter(|c| c.enclosing_calls.contains(&i))
+                .collect();
+            let flattens = inner
+                .iter()
+                .any(|c| FLATTENING_OPERATORS.contains(&c.callee.as_str()));
+            if flattens && !inner.iter().any(|c| c.callee == "catchError") {
+                let name = call.scope.member.as_deref().unwrap_or("createEffect");
+                findings.push(
+                    EFFECT_MISSING_CATCH_ERROR
+                        .finding(format!(
+                            "effect {} に catchError がありません (内側の Observable のエラーで effect が停止します)",
+                            name
+                        ))
+                        .at(path, content, call.range.start, call.range.start),
+                );
+            }
+        }
+    }
+
+    for call in module.calls_to("shareReplay") {
+        let ref_count = call
+            .args
+            .first()
+            .and_then(|config| config.get("refCount"))
+            .and_then(|value| value.as_bool());
+        if ref_count != Some(true) {
+            findings.push(
+                SHARE_REPLAY_WITHOUT_REF_COUNT
+                    .finding("shareReplay に refCount: true が指定されていません")
+                    .at(path, content, call.range.start, call.range.end),
+            );
+        }
+    }
+
+    for call in module
+        .method_calls("toPromise")
+        .filter(|call| is_observable(module, call))
+    {
+        findings.push(
+            TO_PROMISE
+                .finding(
+                    "toPromise() は非推奨です (firstValueFrom / lastValueFrom を使ってください)",
+                )
+                .at(path, content, call.range.start, call.range.end),
+        );
+    }
+
+    for class in module.decorated_classes("Injectable") {
+        for member in &class.members {
+            if member.kind != MemberKind::Property || !member.is_public() {
+                continue;
+            }
+            let created = match member.value.as_ref().map(|v| &v.kind) {
+                Some(ExprKind::New { callee, .. }) => callee.path(),
+                _ => None,
+            };
+            let annotated = member
+                .type_annotation
+                .as_deref()
+                .map(|t| t.split('<').next().unwrap_or(t).trim().to_string());
+            let Some(subject) = created
+                .into_iter()
+                .chain(annotated)
+                .find(|name| SUBJECTS.contains(&name.as_str()))
+            else {
+                continue;
+            };
+            findings.push(
+                PUBLIC_SUBJECT
+                    .finding(format!(
+                        "{}.{} は公開された {} です (private にして asObservable() で公開してください)",
+                        class.name, member.name, subject
+                    ))
+                    .at(path, content, member.range.start, member.range.start),
+            );
+        }
+    }
+}
+
+// toPromise() のレシーバーが Observable か (pipe() の結果、rxjs の生成関数、HttpClient の呼び出し、
+// 末尾が $ の名前、Observable / Subject 型のフィールドのいずれか)
+fn is_observable(module: &Module, call: &Call) -> bool {
+    let Some(receiver) = call.receiver() else {
+        return false;
+    };
+    let class = call
+        .scope
+        .class
+        .as_deref()
+        .and_then(|name| module.classes.iter().find(|c| c.name == name));
+    let type_of = |target: &str| {
+        let field = target.strip_prefix("this.")?;
+        field_type(class?, field)
+    };
+    if let Some(source) = receiver.strip_suffix("()") {
+        return match source.rsplit_once('.') {
+            Some((object, method)) => {
+                method == "pipe" || type_of(object).as_deref() == Some("HttpClient")
+            }
+            None => CREATION_FUNCTIONS.contains(&source) && module.imports_name("rxjs", source),
+        };
+    }
+    receiver.ends_with('$')
+        || type_of(receiver).is_some_and(|t| t == "Observable" || SUBJECTS.contains(&t.as_str()))
+}
+
+// フィールドの型名 (ジェネリクスを除く)。型注釈、コンストラクター引数の型、
+// new X() / inject(X) による初期化から求める
+fn field_type(class: &Class, field: &str) -> Option<String> {
+    let base = |t: &str| t.split('<').next().unwrap_or(t).trim().to_string();
+    for member in &class.members {
+        if member.kind == MemberKind::Constructor
+            && let Some(param) = member.params.iter().find(|p| p.name == field)
+        {
+            return param.type_annotation.as_deref().map(base);
+        }
+        if member.kind != MemberKind::Property || member.name != field {
+            continue;
+        }
+        if let Some(annotation) = &member.type_annotation {
+            return Some(base(annotation));
+        }
+        return match member.value.as_ref().map(|v| &v.kind) {
+            Some(ExprKind::New { callee, .. }) => callee.path(),
+            // private http = inject(HttpClient)
+            Some(ExprKind::Call { callee, args }) if callee.path().as_deref() == Some("inject") => {
+                args.first().and_then(|a| a.path())
+            }
+            _ => None,
+        };
+    }
+    None
+}
+
+// クラス単位の後始末の情報
+struct ClassLifecycle {
+    // 完了する Observable を返すサービスのフィールド (例: "this.http")
+    self_completing: Vec<String>,
+    // ngOnDestroy または DestroyRef.onDestroy の中で unsubscribe されるもの (例: "this.sub")
+    // this.subs.forEach(s => s.unsubscribe()) は配列 (this.subs) を解除したものとして扱う
+    unsubscribed: HashSet<String>,
+}
+
+impl ClassLifecycle {
+    fn new(module: &Module, class: &Class) -> Self {
+        let mut self_completing = Vec::new();
+        for member in &class.members {
+            if member.kind == MemberKind::Constructor {
+                self_completing.extend(
+                    member
+                        .params
+                        .iter()
+                        .filter(|p| {
+                            p.type_annotation
+                                .as_deref()
+                                .is_some_and(|t| SELF_COMPLETING_TYPES.contains(&t))
+                        })
+                        .map(|p| format!("this.{}", p.name)),
+                );
+            }
+            // private http = inject(HttpClient)
+            if let Some(ExprKind::Call { callee, args }) = member.value.as_ref().map(|v| &v.kind)
+                && callee.path().as_deref() == Some("inject")
+                && args
+                    .first()
+                    .and_then(|a| a.path())
+                    .is_some_and(|t| SELF_COMPLETING_TYPES.contains(&t.as_str()))
+            {
+                self_completing.push(format!("this.{}", member.name));
+            }
+        }
+
+        let unsubscribed = module
+            .method_calls("unsubscribe")
+            .filter(|call| call.scope.class.as_deref() == Some(class.name.as_str()))
+            .filter(|call| {
+                call.scope.member.as_deref() == Some("ngOnDestroy")
+                    || call
+                        .enclosing_calls
+                        .iter()
+                        .any(|&i| module.calls[i].method() == "onDestroy")
+            })
+            .filter_map(|call| {
+                let receiver = call.receiver()?;
+                if receiver.starts_with("this.") {
+                    return Some(receiver.to_string());
+                }
+                call.enclosing_calls
+                    .iter()
+                    .rev()
+                    .map(|&i| &module.calls[i])
+                    .find(|outer| outer.method() == "forEach")
+                    .and_then(|outer| outer.receiver())
+                    .filter(|collection| collection.starts_with("this."))
+                    .map(str::to_string)
+            })
+            .collect();
+
+        Self {
+            self_completing,
+            unsubscribed,
+        }
+    }
+
+    fn is_released(&self, module: &Module, call: &Call) -> bool {
+        // レシーバー側 (".subscribe(" より前) にあるオペレーター
+        let receiver_end = call.args.first().map_or(call.range.end, |a| a.range.start);
+        let operators: Vec<&str> = module
+            .calls
+            .iter()
+            .filter(|c| c.range.start >= call.range.start && c.range.end <= receiver_end)
+            .map(|c| c.callee.as_str())
+            .collect();
+        if operators.iter().any(|c| COMPLETING_OPERATORS.contains(c)) {
+            return true;
+        }
+        let self_completing = self
+            .self_completing
+            .iter()
+            .any(|field| call.callee.starts_with(&format!("{}.", field)));
+        let extended = operators
+            .iter()
+            .any(|c| FLATTENING_OPERATORS.contains(c) || EXTENDING_OPERATORS.contains(c));
+        if self_completing && !extended {
+            return true;
+        }
+
+        // this.sub = x.subscribe() / this.subs.push(x.subscribe()) / this.subs.add(x.subscribe()) の
+        // 保存先が破棄時に unsubscribe される
+        let stored = match call.enclosing_calls.last().map(|&i| &module.calls[i]) {
+            Some(outer) if matches!(outer.method(), "add" | "push") => {
+                outer.receiver().map(str::to_string)
+            }
+            _ => match call.assigned_to.as_deref() {
+                // const sub = x.subscribe(); this.subs.push(sub);
+                Some(local) if !local.contains('.') => stored_local(module, call, local),
+                target => target.map(str::to_string),
+            },
+        };
+        stored.is_some_and(|target| self.unsubscribed.contains(&target))
+    }
+}
+
+// ローカル変数に受けた購読を、同じメソッドの中で add / push した先
+fn stored_local(module: &Module, call: &Call, local: &str) -> Option<String> {
+    module
+        .calls
+        .iter()
+        .filter(|c| c.scope == call.scope && matches!(c.method(), "add" | "push"))
+        .find(|c| c.args.iter().any(|a| a.path().as_deref() == Some(local)))
+        .and_then(|c| c.receiver())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    fn analyze(files: &[(&str, &str)]) -> AnalysisResult {
+        let project = TempProject::new(files);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Default::default(),
+        };
+        RxjsAnalyzer.analyze(&ctx).unwrap()
+    }
+
+    fn leaks(result: &AnalysisResult) -> Vec<&str> {
+        result
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == SUBSCRIPTION_LEAK.id)
+            .map(|f| f.message.split(' ').next().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn released_subscriptions() {
+        let result = analyze(&[(
+            "src/a.component.ts",
+            r#"@Component({ selector: 'a' })
+export class A {
+  private http = inject(HttpClient);
+  private sub: Subscription;
+  private subs: Subscription[] = [];
+  private others: Subscription[] = [];
+  ngOnInit() {
+    this.sub = this.store.value$.subscribe();
+    this.subs.push(this.store.a$.subscribe());
+    this.others.push(this.store.b$.subscribe());
+    this.store.c$.pipe(takeUntilDestroyed()).subscribe();
+    this.store.d$.pipe(take(1)).subscribe();
+    this.http.get('/x').subscribe();
+  }
+  ngOnDestroy() {
+    this.sub.unsubscribe();
+    this.subs.forEach(s => s.unsubscribe());
+    const local = this.others[0];
+    local.unsubscribe();
+  }
+}
+"#,
+        )]);
+        // 配列の forEach で解除したものだけが解除済みで、take(1) は解除とみなさない
+        assert_eq!(leaks(&result), ["A.ngOnInit", "A.ngOnInit"]);
+        let lines: Vec<usize> = result
+            .findings
+            .iter()
+            .filter_map(|f| f.location.as_ref().map(|l| l.span.start_line))
+            .collect();
+        assert_eq!(lines, [10, 12]);
+    }
+
+    #[test]
+    fn async_pipe_subscriptions() {
+        let result = analyze(&[
+            (
+                "src/a.component.ts",
+                "@Component({ selector: 'a', templateUrl: './a.component.html' })\nexport class A {}\n",
+            ),
+            (
+                "src/a.component.html",
+                "<p>{{ user$ | async }}</p>\n@if (items$ | async; as items) { {{ items.length }} }\n",
+            ),
+            (
+                "src/b.component.ts",
+                "@Component({ selector: 'b', template: '<p>{{ x }}</p>' })\nexport class B {\n  ngOnInit() { this.x$.subscribe(); }\n}\n",
+            ),
+        ]);
+        assert_eq!(leaks(&result), ["B.ngOnInit"]);
+        assert!(
+            result.details.contains(
+                &"コンポーネント・ディレクティブの購読数: 3 (subscribe() 1 / async パイプ 2)"
+                    .to_string()
+            )
+        );
+        assert!(
+            result
+                .details
+                .contains(&"async パイプで購読しているコンポーネント数: 1".to_string())
+        );
+    }
+
+    #[test]
+    fn stored_subscriptions_and_extended_lifetimes() {
+        let result = analyze(&[(
+            "src/a.component.ts",
+            r#"@Component({ selector: 'a' })
+export class A {
+  private sub = new Subscription();
+  private subs: Subscription[] = [];
+  private poll: Subscription;
+  constructor(private route: ActivatedRoute) {}
+  ngOnInit() {
+    this.poll =
+      this.store.value$
+        .pipe(map(v => v))
+        .subscribe();
+    this.sub.add(
+      this.store.a$.subscribe()
+    );
+    const local = this.store.b$.subscribe();
+    this.subs.push(local);
+    this.route.params.subscribe();
+    this.route.params.pipe(switchMap(() => interval(1000))).subscribe();
+  }
+  ngOnDestroy() {
+    this.poll.unsubscribe();
+    this.sub.unsubscribe();
+    this.subs.forEach(s => s.unsubscribe());
+  }
+}
+"#,
+        )]);
+        // 複数行の代入・add・ローカル変数経由の push は解除済み。
+        // ActivatedRoute でも switchMap で interval を購読し続けるものは解除が必要
+        assert_eq!(leaks(&result), ["A.ngOnInit"]);
+        let location = result.findings[0].location.as_ref().unwrap();
+        assert_eq!(location.span.start_line, 18);
+    }
+
+    fn rule_lines(result: &AnalysisResult) -> Vec<(&str, usize)> {
+        result
+            .findings
+            .iter()
+            .filter(|f| f.rule_id != SUBSCRIPTION_LEAK.id)
+            .map(|f| {
+                (
+                    f.rule_id.as_str(),
+                    f.location.as_ref().unwrap().span.start_line,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn anti_patterns() {
+        let result = analyze(&[
+            (
+                "src/a.service.ts",
+                r#"import { of, timer } from 'rxjs';
+@Injectable({ providedIn: 'root' })
+export class AService {
+  readonly changes = new Subject<string>();
+  private readonly state = new BehaviorSubject(0);
+  private http = inject(HttpClient);
+  load() {
+    this.user$.subscribe(user => {
+      this.http.get(user.id).subscribe();
+    });
+    this.user$.pipe(tap(user => this.log$.subscribe())).subscribe();
+    this.user$.pipe(shareReplay(1));
+    this.user$.pipe(shareReplay({ bufferSize: 1, refCount: true }));
+    this.user$.pipe(map(u => u)).toPromise();
+    of(1).toPromise();
+    this.http.get('/x').toPromise();
+    this.state.toPromise();
+    this.client.toPromise();
+    timer.toPromise();
+  }
+}
+"#,
+            ),
+            (
+                "src/a.effects.ts",
+                r#"import { createEffect } from '@ngrx/effects';
+export class AEffects {
+  load$ = createEffect(() => this.actions$.pipe(switchMap(() => this.api.load())));
+  safe$ = createEffect(() =>
+    this.actions$.pipe(switchMap(() => this.api.load()), catchError(() => EMPTY))
+  );
+}
+"#,
+            ),
+        ]);
+        // レシーバーが Observable と分からない toPromise() は報告しない
+        assert_eq!(
+            rule_lines(&result),
+            [
+                (EFFECT_MISSING_CATCH_ERROR.id, 3),
+                (SUBSCRIBE_IN_SUBSCRIBE.id, 9),
+                (NESTED_SUBSCRIBE.id, 11),
+                (SHARE_REPLAY_WITHOUT_REF_COUNT.id, 12),
+                (TO_PROMISE.id, 14),
+                (TO_PROMISE.id, 15),
+                (TO_PROMISE.id, 16),
+                (TO_PROMISE.id, 17),
+                (PUBLIC_SUBJECT.id, 4),
+            ]
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use rayon::prelude::*;
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Finding, Rule, Severity};
+use crate::index::{FileKind, SourceFile};
+
+const CATEGORY: &str = "セキュリティ";
+
+const INNER_HTML: Rule = Rule {
+    id: "security/inner-html",
+    severity: Severity::Warning,
+    description: "innerHTML の使用・テンプレートの [innerHTML] バインディング (XSS リスク)",
+};
+
+const EVAL: Rule = Rule {
+    id: "security/eval",
+    severity: Severity::Error,
+    description: "eval() の使用",
+};
+
+const MISSING_SANITIZATION: Rule = Rule {
+    id: "security/missing-sanitization",
+    severity: Severity::Warning,
+    description: "DomSanitizer 等による入力値のサニタイズが見つからない",
+};
+
+const RULES: &[Rule] = &[INNER_HTML, EVAL, MISSING_SANITIZATION];
+
+// セキュリティ解析
+pub struct SecurityAnalyzer;
+
+impl Analyzer for SecurityAnalyzer {
+    fn id(&self) -> &str {
+        "security"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        // ファイルごとの検査は並列に行い、ファイル順にまとめる
+        let scans: Vec<FileScan> = ctx.index.par_files(FileKind::Ts).map(scan_file).collect();
+        let sanitization_found = scans.iter().any(|s| s.sanitization);
+        let csrf_protection = scans.iter().any(|s| s.csrf);
+        let https_enforcement = scans.iter().any(|s| s.https);
+        let mut dangerous_patterns: Vec<Finding> =
+            scans.into_iter().flat_map(|s| s.findings).collect();
+
+        // テンプレートの [innerHTML] バインディング
+        for component in ctx.index.templates() {
+            let Some(template) = &component.template else {
+                continue;
+            };
+            let Some(content) = ctx.index.get(&component.file).and_then(|f| f.content()) else {
+                continue;
+            };
+            for element in template.elements() {
+                if let Some(binding) = element.property_binding("innerHTML") {
+                    dangerous_patterns.push(
+                        INNER_HTML
+                            .finding(format!(
+                                "{} のテンプレートで innerHTML バインディングが検出されました（XSSリスクあり）",
+                                component.component
+                            ))
+                            .at(&component.file, content, binding.range.start, binding.range.end),
+                    );
+                }
+            }
+        }
+
+        result.details.push(format!(
+            "入力値サニタイズ: {}",
+            if sanitization_found {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "CSRF対策: {}",
+            if csrf_protection {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "HTTPS強制: {}",
+            if https_enforcement {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+
+        for finding in dangerous_patterns {
+            result.push(finding);
+        }
+
+        if !sanitization_found {
+            result.push(MISSING_SANITIZATION.finding("入力値のサニタイズ処理が確認できません"));
+        }
+
+        Ok(result)
+    }
+}
+
+// 1ファイル分の検査結果
+#[derive(Default)]
+struct FileScan {
+    sanitization: bool,
+    csrf: bool,
+    https: bool,
+    findings: Vec<Finding>,
+}
+
+fn scan_file(file: &SourceFile) -> FileScan {
+    let mut scan = FileScan::default();
+    let (Some(content), Some(module)) = (file.content(), file.module()) else {
+        return scan;
+    };
+
+    // サニタイズ処理のチェック
+    scan.sanitization =
+        module.any_name(|n| n.contains("sanitize")) || module.has_name("DomSanitizer");
+
+    // CSRF対策のチェック
+    scan.csrf = module.any_name(|n| n.contains("csrf") || n.contains("CSRF"));
+
+    // HTTPS強制のチェック
+    scan.https = module.strings.iter().any(|s| s.value.starts_with("https"))
+        && module.any_name(|n| n.contains("redirect"));
+
+    // 危険なパターンのチェック (文字列やコメント中の記述は対象外)
+    for access in module.member_accesses("innerHTML") {
+        scan.findings.push(
+            INNER_HTML
+                .finding("innerHTML使用が検出されました（XSSリスクあり）")
+                .at(&file.path, content, access.range.start, access.range.end),
+        );
+    }
+
+    for call in module.calls_to("eval") {
+        let start = call.range.start;
+        scan.findings.push(
+            EVAL.finding("eval()関数の使用が検出されました（セキュリティリスクあり）")
+                .at(&file.path, content, start, start + "eval".len()),
+        );
+    }
+    scan
+}
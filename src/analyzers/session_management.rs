@@ -0,0 +1,89 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::index::FileKind;
+
+const CATEGORY: &str = "セッション管理";
+
+const NO_TIMEOUT: Rule = Rule {
+    id: "session-management/no-timeout",
+    severity: Severity::Warning,
+    description: "セッションタイムアウト処理が見つからない",
+};
+
+const RULES: &[Rule] = &[NO_TIMEOUT];
+
+// セッション管理解析
+pub struct SessionManagementAnalyzer;
+
+impl Analyzer for SessionManagementAnalyzer {
+    fn id(&self) -> &str {
+        "session_management"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        let mut session_timeout = false;
+        let mut auto_logout = false;
+        let mut session_storage_usage = false;
+
+        for file in ctx.index.files(FileKind::Ts) {
+            if let Some(module) = file.module() {
+                if module.any_name(|n| n.contains("timeout") || n.contains("expire")) {
+                    session_timeout = true;
+                }
+
+                if module.any_name(|n| n.contains("logout"))
+                    && module.any_name(|n| n.contains("auto"))
+                {
+                    auto_logout = true;
+                }
+
+                if module.has_name("sessionStorage") {
+                    session_storage_usage = true;
+                }
+            }
+        }
+
+        result.details.push(format!(
+            "セッションタイムアウト: {}",
+            if session_timeout {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "自動ログアウト: {}",
+            if auto_logout {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "セッションストレージ使用: {}",
+            if session_storage_usage {
+                "確認済み"
+            } else {
+                "未確認"
+            }
+        ));
+
+        if !session_timeout {
+            result.push(NO_TIMEOUT.finding("セッションタイムアウト機能が確認できません"));
+        }
+
+        Ok(result)
+    }
+}
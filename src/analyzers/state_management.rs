@@ -0,0 +1,397 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::index::FileKind;
+use crate::packages::Packages;
+use crate::table::Table;
+use crate::ts::{Call, ExprKind, MemberKind, Module};
+
+const CATEGORY: &str = "状態管理";
+
+const NO_STATE_PATTERN: Rule = Rule {
+    id: "state-management/no-state-pattern",
+    severity: Severity::Warning,
+    description: "状態管理ライブラリまたはパターンが見つからない",
+};
+
+const EFFECT_WRITES_SIGNAL: Rule = Rule {
+    id: "state-management/effect-writes-signal",
+    severity: Severity::Warning,
+    description: "effect() の中でシグナルに書き込んでいる (computed / linkedSignal で導出できないか)",
+};
+
+const EFFECT_OUTSIDE_INJECTION_CONTEXT: Rule = Rule {
+    id: "state-management/effect-outside-injection-context",
+    severity: Severity::Warning,
+    description: "注入コンテキストの外 (メソッドやモジュールのトップレベル) で injector を指定せずに呼ばれた effect()",
+};
+
+const RULES: &[Rule] = &[
+    NO_STATE_PATTERN,
+    EFFECT_WRITES_SIGNAL,
+    EFFECT_OUTSIDE_INJECTION_CONTEXT,
+];
+
+// シグナル関連 API (表の行名, import 元, 呼び出しの表記)
+const SIGNAL_APIS: &[(&str, &str, &[&str])] = &[
+    ("signal", "@angular/core", &["signal"]),
+    ("computed", "@angular/core", &["computed"]),
+    ("effect", "@angular/core", &["effect"]),
+    ("input", "@angular/core", &["input", "input.required"]),
+    ("output", "@angular/core", &["output"]),
+    ("model", "@angular/core", &["model", "model.required"]),
+    ("toSignal", "@angular/core/rxjs-interop", &["toSignal"]),
+    (
+        "toObservable",
+        "@angular/core/rxjs-interop",
+        &["toObservable"],
+    ),
+    ("signalStore", "@ngrx/signals", &["signalStore"]),
+];
+
+// 書き込み可能なシグナルを作る関数と、書き込みのメソッド
+const WRITABLE_SIGNALS: &[&str] = &["signal", "model", "model.required", "linkedSignal"];
+const SIGNAL_WRITES: &[&str] = &["set", "update"];
+
+// 状態管理解析
+pub struct StateManagementAnalyzer;
+
+impl Analyzer for StateManagementAnalyzer {
+    fn id(&self) -> &str {
+        "state_management"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        let mut ngrx_import = false;
+        let mut akita_import = false;
+        let mut service_usage = false;
+        let mut subject_usage = false;
+        // SIGNAL_APIS ごとの (呼び出し数, ファイル数)
+        let mut signal_counts = vec![(0, 0); SIGNAL_APIS.len()];
+
+        for file in ctx.index.files(FileKind::Ts) {
+            if let Some(module) = file.module() {
+                for ((_, source, callees), counts) in SIGNAL_APIS.iter().zip(&mut signal_counts) {
+                    let calls = callees
+                        .iter()
+                        .filter(|callee| imports_function(module, source, callee))
+                        .map(|callee| module.calls_to(callee).count())
+                        .sum::<usize>();
+                    if calls > 0 {
+                        counts.0 += calls;
+                        counts.1 += 1;
+                    }
+                }
+
+                if imports_function(module, "@angular/core", "effect") {
+                    let content = file.content().unwrap_or_default();
+                    for (i, call) in module.calls.iter().enumerate() {
+                        if call.callee != "effect" {
+                            continue;
+                        }
+                        if outside_injection_context(module, call) {
+                            let place =
+                                match call.scope.member.as_ref().or(call.scope.function.as_ref()) {
+                                    Some(name) => format!("{} の中", name),
+                                    None => "モジュールのトップレベル".to_string(),
+                                };
+                            result.push(
+                                EFFECT_OUTSIDE_INJECTION_CONTEXT
+                                    .finding(format!(
+                                        "{}で effect() を injector 無しで呼んでいます (注入コンテキストの外では NG0203 になります)",
+                                        place
+                                    ))
+                                    .at(&file.path, content, call.range.start, call.range.end),
+                            );
+                        }
+                        for write in signal_writes(module, i) {
+                            result.push(
+                                EFFECT_WRITES_SIGNAL
+                                    .finding(format!(
+                                        "effect() の中でシグナル {} に書き込んでいます",
+                                        write.receiver().unwrap_or_default()
+                                    ))
+                                    .at(&file.path, content, write.range.start, write.range.end),
+                            );
+                        }
+                    }
+                }
+
+                if module.imports_from("@ngrx/").next().is_some() {
+                    ngrx_import = true;
+                }
+
+                if module.imports.iter().any(|i| i.source.contains("akita")) {
+                    akita_import = true;
+                }
+
+                if module.decorated_classes("Injectable").next().is_some() {
+                    service_usage = true;
+                }
+
+                if module.any_name(|n| n.ends_with("Subject")) {
+                    subject_usage = true;
+                }
+            }
+        }
+
+        // package.json があれば依存から判定し、無ければ import から推定する
+        let packages = ctx.index.packages();
+        let ngrx_usage = library_usage(
+            &mut result,
+            "NgRx",
+            packages,
+            &["@ngrx/store", "@ngrx/signals"],
+            ngrx_import,
+        );
+        let akita_usage = library_usage(
+            &mut result,
+            "Akita",
+            packages,
+            &["@datorama/akita"],
+            akita_import,
+        );
+        result.details.push(format!(
+            "サービス実装: {}",
+            if service_usage {
+                "確認済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "Subject使用: {}",
+            if subject_usage {
+                "確認済み"
+            } else {
+                "未確認"
+            }
+        ));
+
+        // SIGNAL_APIS の行をラベルで引いた呼び出し数
+        let calls = |label: &str| {
+            SIGNAL_APIS
+                .iter()
+                .position(|(l, ..)| *l == label)
+                .map_or(0, |i| signal_counts[i].0)
+        };
+        let signal_usage = calls("signal") > 0;
+        result.details.push(format!(
+            "シグナル使用: {}",
+            if signal_usage {
+                "確認済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "NgRx SignalStore使用: {}",
+            if calls("signalStore") > 0 {
+                "確認済み"
+            } else {
+                "未確認"
+            }
+        ));
+        let mut table = Table::new(
+            "シグナル API の使用状況",
+            &["API", "呼び出し数", "ファイル数"],
+        );
+        for ((label, _, _), (calls, files)) in SIGNAL_APIS.iter().zip(&signal_counts) {
+            table.push_row(vec![
+                label.to_string(),
+                calls.to_string(),
+                files.to_string(),
+            ]);
+        }
+        result.tables.push(table);
+
+        if !ngrx_usage && !akita_usage && !subject_usage && !signal_usage {
+            result
+                .push(NO_STATE_PATTERN.finding("状態管理ライブラリまたはパターンが確認できません"));
+        }
+
+        Ok(result)
+    }
+}
+
+// 依存に含まれるかどうかを詳細に書き出して返す
+fn library_usage(
+    result: &mut AnalysisResult,
+    label: &str,
+    packages: Option<&Packages>,
+    names: &[&str],
+    imported: bool,
+) -> bool {
+    let Some(packages) = packages else {
+        result.details.push(format!(
+            "{}使用: {}",
+            label,
+            if imported {
+                "確認済み"
+            } else {
+                "未確認"
+            }
+        ));
+        return imported;
+    };
+    let found: Vec<String> = names
+        .iter()
+        .filter_map(|name| packages.get(name))
+        .map(|d| format!("{} {}", d.name, d.installed.as_deref().unwrap_or(&d.range)))
+        .collect();
+    if found.is_empty() {
+        result.details.push(format!("{}使用: 未確認", label));
+        false
+    } else {
+        result
+            .details
+            .push(format!("{}使用: 確認済み ({})", label, found.join(", ")));
+        true
+    }
+}
+
+// "input.required" のような表記は先頭の名前が import されているかで判定する
+fn imports_function(module: &Module, source: &str, callee: &str) -> bool {
+    let name = callee.split('.').next().unwrap_or(callee);
+    module.imports_name(source, name)
+}
+
+// コンストラクターとフィールド初期化子、トップレベル関数 (inject 系のヘルパー) 以外の effect()
+// (それらの中でも、アロー関数などの関数式の中は呼ばれる時点が分からないため外とみなす)
+fn outside_injection_context(module: &Module, call: &Call) -> bool {
+    if call.args.get(1).and_then(|o| o.get("injector")).is_some()
+        || call
+            .enclosing_calls
+            .iter()
+            .any(|&i| module.calls[i].method() == "runInInjectionContext")
+    {
+        return false;
+    }
+    if call.scope.in_closure {
+        return true;
+    }
+    match call.scope.member_kind {
+        Some(MemberKind::Constructor | MemberKind::Property) => false,
+        Some(_) => true,
+        None => call.scope.class.is_none() && call.scope.function.is_none(),
+    }
+}
+
+// effect() の引数の中で、書き込み可能なシグナル (クラスのフィールドかトップレベルの変数) への set / update
+fn signal_writes(module: &Module, effect: usize) -> impl Iterator<Item = &Call> {
+    let is_signal = move |call: &Call| {
+        let Some(receiver) = call.receiver() else {
+            return false;
+        };
+        let init = match receiver.strip_prefix("this.") {
+            Some(field) => module
+                .classes
+                .iter()
+                .filter(|c| call.scope.class.as_deref() == Some(c.name.as_str()))
+                .find_map(|c| c.member(field))
+                .and_then(|m| m.value.as_ref()),
+            None => module.variable(receiver).and_then(|v| v.init.as_ref()),
+        };
+        init.and_then(|e| match &e.kind {
+            ExprKind::Call { callee, .. } => callee.path(),
+            _ => None,
+        })
+        .is_some_and(|path| WRITABLE_SIGNALS.contains(&path.as_str()))
+    };
+    module.calls.iter().filter(move |call| {
+        call.enclosing_calls.contains(&effect)
+            && SIGNAL_WRITES.contains(&call.method())
+            // untracked(() => ...) の中の書き込みは意図したものとみなす
+            && !call
+                .enclosing_calls
+                .iter()
+                .any(|&i| module.calls[i].callee == "untracked")
+            && is_signal(call)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    fn analyze(source: &str) -> AnalysisResult {
+        let project = TempProject::new(&[("src/a.component.ts", source)]);
+        let ctx = ProjectContext {
+            project_path: project.root.clone(),
+            index: project.index(),
+            config: Default::default(),
+        };
+        StateManagementAnalyzer.analyze(&ctx).unwrap()
+    }
+
+    fn lines(result: &AnalysisResult, rule: &Rule) -> Vec<usize> {
+        result
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == rule.id)
+            .map(|f| f.location.as_ref().unwrap().span.start_line)
+            .collect()
+    }
+
+    #[test]
+    fn effect_writes_signal() {
+        let result = analyze(
+            r#"import { effect, signal, computed, untracked } from '@angular/core';
+export class A {
+  count = signal(0);
+  double = computed(() => this.count() * 2);
+  log = effect(() => {
+    this.count.set(1);
+    this.double.set(2);
+    untracked(() => this.count.update(v => v + 1));
+  });
+}
+const total = signal(0);
+export function setup() {
+  effect(() => total.update(v => v + 1));
+}
+"#,
+        );
+        // computed への set と untracked の中の書き込みは報告しない
+        assert_eq!(lines(&result, &EFFECT_WRITES_SIGNAL), [6, 13]);
+        assert!(lines(&result, &EFFECT_OUTSIDE_INJECTION_CONTEXT).is_empty());
+    }
+
+    #[test]
+    fn effect_outside_injection_context() {
+        let result = analyze(
+            r#"import { effect } from '@angular/core';
+export class A {
+  a = effect(() => {});
+  onClick = () => effect(() => {});
+  constructor(private injector: Injector) {
+    effect(() => {});
+    setTimeout(() => effect(() => {}));
+  }
+  ngOnInit() {
+    effect(() => {}, { injector: this.injector });
+    runInInjectionContext(this.injector, () => effect(() => {}));
+    effect(() => {});
+  }
+}
+effect(() => {});
+"#,
+        );
+        assert_eq!(
+            lines(&result, &EFFECT_OUTSIDE_INJECTION_CONTEXT),
+            [4, 7, 12, 15]
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::index::FileKind;
+
+const CATEGORY: &str = "UI/UX・デザインシステム";
+
+// UI/UXデザインシステム解析
+pub struct UiDesignSystemAnalyzer;
+
+impl Analyzer for UiDesignSystemAnalyzer {
+    fn id(&self) -> &str {
+        "ui_design_system"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        if ctx.index.count(FileKind::Scss) + ctx.index.count(FileKind::Css) == 0 {
+            return Ok(AnalysisResult::skipped(
+                CATEGORY,
+                "スタイルファイルが無いため解析をスキップしました",
+            ));
+        }
+
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        let mut design_tokens = false;
+        let mut component_library = false;
+        let mut theme_support = false;
+
+        let style_files = ctx
+            .index
+            .files(FileKind::Scss)
+            .chain(ctx.index.files(FileKind::Css));
+        for file in style_files {
+            if let Some(content) = file.content() {
+                if content.contains("$primary") || content.contains("--primary") {
+                    design_tokens = true;
+                }
+
+                if content.contains("@mixin") || content.contains("@include") {
+                    component_library = true;
+                }
+
+                if content.contains("theme") || content.contains("dark") {
+                    theme_support = true;
+                }
+            }
+        }
+
+        result.details.push(format!(
+            "デザイントークン: {}",
+            if design_tokens {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "コンポーネントライブラリ: {}",
+            if component_library {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+        result.details.push(format!(
+            "テーマサポート: {}",
+            if theme_support {
+                "実装済み"
+            } else {
+                "未確認"
+            }
+        ));
+
+        Ok(result)
+    }
+}
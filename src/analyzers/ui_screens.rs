@@ -0,0 +1,217 @@
+use regex::Regex;
+use std::io::Result;
+
+use crate::analyzer::{AnalysisResult, Analyzer, ProjectContext};
+use crate::finding::{Rule, Severity};
+use crate::index::{FileKind, ProjectIndex};
+use crate::table::Table;
+use crate::template::AttributeKind;
+
+const CATEGORY: &str = "画面";
+
+const RESPONSIVE_DESIGN_MISSING: Rule = Rule {
+    id: "ui-screens/responsive-design-missing",
+    severity: Severity::Warning,
+    description: "レスポンシブデザイン (@media) が見つからない",
+};
+
+const MISSING_TEMPLATE: Rule = Rule {
+    id: "ui-screens/missing-template",
+    severity: Severity::Error,
+    description: "templateUrl で参照するテンプレートファイルが存在しない",
+};
+
+const RULES: &[Rule] = &[RESPONSIVE_DESIGN_MISSING, MISSING_TEMPLATE];
+
+// 画面解析
+pub struct UiScreensAnalyzer;
+
+impl Analyzer for UiScreensAnalyzer {
+    fn id(&self) -> &str {
+        "ui_screens"
+    }
+
+    fn category(&self) -> &str {
+        CATEGORY
+    }
+
+    fn rules(&self) -> &[Rule] {
+        RULES
+    }
+
+    fn analyze(&self, ctx: &ProjectContext) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::new(CATEGORY);
+
+        // HTMLファイルを検索
+        let html_count = ctx.index.count(FileKind::Html);
+        result
+            .details
+            .push(format!("HTMLファイル数: {}", html_count));
+
+        // @Component デコレーターからコンポーネントを抽出
+        let components = ctx.index.components();
+        result
+            .details
+            .push(format!("Angularコンポーネント数: {}", components.len()));
+        // 命名規約 (*.component.ts) に沿わないファイルにあるコンポーネントの数
+        let unconventional = components
+            .iter()
+            .filter(|c| !c.file.ends_with(FileKind::ComponentTs.suffix()))
+            .count();
+        result.details.push(format!(
+            "コンポーネントファイル (*.component.ts) 数: {} (それ以外のファイルにあるコンポーネント: {})",
+            ctx.index.count(FileKind::ComponentTs),
+            unconventional
+        ));
+        if !components.is_empty() {
+            result.tables.push(component_table(&ctx.index));
+        }
+
+        // コンポーネントテンプレートの解析
+        let templates = ctx.index.templates();
+        let inline_count = templates.iter().filter(|t| t.inline).count();
+        result.details.push(format!(
+            "コンポーネントテンプレート数: {} (インライン: {}, 外部: {})",
+            templates.len(),
+            inline_count,
+            templates.len() - inline_count
+        ));
+
+        let mut control_flow_blocks = 0;
+        let mut structural_directives = 0;
+        for component in templates {
+            let Some(template) = &component.template else {
+                if let Some(content) = ctx
+                    .index
+                    .get(&component.component_file)
+                    .and_then(|f| f.content())
+                {
+                    result.push(
+                        MISSING_TEMPLATE
+                            .finding(format!(
+                                "{} のテンプレートファイルが見つかりません: {}",
+                                component.component, component.file
+                            ))
+                            .at(
+                                &component.component_file,
+                                content,
+                                component.range.start,
+                                component.range.end,
+                            ),
+                    );
+                }
+                continue;
+            };
+            control_flow_blocks += template
+                .blocks()
+                .iter()
+                .filter(|block| matches!(block.name.as_str(), "if" | "for" | "switch" | "defer"))
+                .count();
+            structural_directives += template
+                .elements()
+                .iter()
+                .flat_map(|element| &element.attributes)
+                .filter(|attribute| attribute.kind == AttributeKind::Structural)
+                .count();
+        }
+        result.details.push(format!(
+            "制御フローブロック数 (@if/@for/@switch/@defer): {}",
+            control_flow_blocks
+        ));
+        result.details.push(format!(
+            "構造ディレクティブ数 (*ngIf/*ngFor 等): {}",
+            structural_directives
+        ));
+
+        // CSSファイルを検索
+        let css_count = ctx.index.count(FileKind::Css);
+        let scss_count = ctx.index.count(FileKind::Scss);
+        result.details.push(format!(
+            "スタイルファイル数: {} (CSS: {}, SCSS: {})",
+            css_count + scss_count,
+            css_count,
+            scss_count
+        ));
+
+        // レスポンシブ対応チェック
+        if check_responsive_design(&ctx.index) {
+            result
+                .details
+                .push("レスポンシブデザイン: 実装済み".to_string());
+        } else {
+            result.push(
+                RESPONSIVE_DESIGN_MISSING.finding("レスポンシブデザインの実装が確認できません"),
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+// コンポーネント一覧
+fn component_table(index: &ProjectIndex) -> Table {
+    let mut table = Table::new(
+        "コンポーネント一覧",
+        &[
+            "コンポーネント",
+            "ファイル",
+            "セレクター",
+            "standalone",
+            "変更検知",
+            "入力",
+            "出力",
+            "テンプレート",
+            "スタイル",
+            "imports",
+            "行数",
+            "テンプレート行数",
+        ],
+    );
+    for component in index.components() {
+        let template = match (&component.template_url, component.inline_template) {
+            (Some(url), _) => index.relative(url).to_string(),
+            (None, true) => "(インライン)".to_string(),
+            (None, false) => String::new(),
+        };
+        let mut styles: Vec<&str> = component
+            .style_urls
+            .iter()
+            .map(|url| index.relative(url))
+            .collect();
+        if component.inline_styles {
+            styles.push("(インライン)");
+        }
+        table.push_row(vec![
+            component.name.clone(),
+            index.relative(&component.file).to_string(),
+            component.selector.clone().unwrap_or_default(),
+            match component.standalone {
+                Some(true) => "true".to_string(),
+                Some(false) => "false".to_string(),
+                None => "(未指定)".to_string(),
+            },
+            component
+                .change_detection
+                .clone()
+                .unwrap_or_else(|| "Default".to_string()),
+            component.inputs.join(", "),
+            component.outputs.join(", "),
+            template,
+            styles.join(", "),
+            component.imports.join(", "),
+            component.lines.to_string(),
+            component.template_lines.to_string(),
+        ]);
+    }
+    table
+}
+
+fn check_responsive_design(index: &ProjectIndex) -> bool {
+    let media_query_regex = Regex::new(r"@media\s*\([^)]*\)").unwrap();
+
+    index
+        .files(FileKind::Css)
+        .chain(index.files(FileKind::Scss))
+        .filter_map(|file| file.content())
+        .any(|content| media_query_regex.is_match(content))
+}
@@ -0,0 +1,210 @@
+use crate::index::{FileKind, ProjectIndex, resolve_path};
+use crate::packages::Version;
+use crate::ts::{Class, Expr, ExprKind, TextRange};
+
+// standalone の指定を省略したときに standalone になる Angular のバージョン
+pub const STANDALONE_DEFAULT_SINCE: u64 = 19;
+
+// @Component デコレーターから抽出したコンポーネント情報
+#[derive(Debug, Clone)]
+pub struct Component {
+    pub name: String,
+    pub file: String,
+    pub selector: Option<String>,
+    // standalone の明示的な指定 (省略時は None)
+    pub standalone: Option<bool>,
+    // "OnPush" / "Default" (省略時は None)
+    pub change_detection: Option<String>,
+    // @Input() と input() / model() による入力
+    pub inputs: Vec<String>,
+    // @Output() と output() / model() による出力
+    pub outputs: Vec<String>,
+    // templateUrl の解決済みパス (インラインテンプレートは None で inline_template が true)
+    pub template_url: Option<String>,
+    pub inline_template: bool,
+    // styleUrl(s) の解決済みパス
+    pub style_urls: Vec<String>,
+    pub inline_styles: bool,
+    // imports 配列に並んだ名前
+    pub imports: Vec<String>,
+    // コンポーネントのファイルとテンプレートの行数
+    pub lines: usize,
+    pub template_lines: usize,
+    // クラス宣言の位置
+    pub range: TextRange,
+}
+
+impl Component {
+    pub fn is_on_push(&self) -> bool {
+        self.change_detection.as_deref() == Some("OnPush")
+    }
+
+    // 指定が無ければ Angular のバージョンで決まる。バージョンが分からない場合は imports を持つものを standalone とみなす
+    pub fn is_standalone(&self, angular: Option<Version>) -> bool {
+        let default = match angular {
+            Some(version) => version.major >= STANDALONE_DEFAULT_SINCE,
+            None => !self.imports.is_empty(),
+        };
+        self.standalone.unwrap_or(default)
+    }
+}
+
+// プロジェクト内の全コンポーネントを抽出する
+pub fn collect_components(index: &ProjectIndex) -> Vec<Component> {
+    let mut components = Vec::new();
+    for file in index.files(FileKind::Ts) {
+        let (Some(content), Some(module)) = (file.content(), file.module()) else {
+            continue;
+        };
+        for class in module.decorated_classes("Component") {
+            components.push(extract(index, &file.path, content, class));
+        }
+    }
+    components
+}
+
+fn extract(index: &ProjectIndex, path: &str, content: &str, class: &Class) -> Component {
+    let metadata = class
+        .decorator("Component")
+        .and_then(|d| d.metadata())
+        .unwrap_or_default();
+    let get = |key: &str| metadata.iter().find(|p| p.key == key).map(|p| &p.value);
+
+    let mut component = Component {
+        name: class.name.clone(),
+        file: path.to_string(),
+        selector: get("selector").and_then(Expr::as_str).map(str::to_string),
+        standalone: get("standalone").and_then(Expr::as_bool),
+        change_detection: get("changeDetection")
+            .and_then(Expr::path)
+            .map(|p| p.rsplit('.').next().unwrap_or(&p).to_string()),
+        inputs: names_in(get("inputs")),
+        outputs: names_in(get("outputs")),
+        template_url: None,
+        inline_template: false,
+        style_urls: Vec::new(),
+        inline_styles: get("styles").is_some(),
+        imports: get("imports")
+            .map(|imports| {
+                imports
+                    .items()
+                    .iter()
+                    .filter_map(|item| item.path().or_else(|| spread_path(item)))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        lines: content.lines().count(),
+        template_lines: 0,
+        range: class.range,
+    };
+
+    if let Some(template) = get("template") {
+        component.inline_template = true;
+        component.template_lines = content[template.range.start..template.range.end]
+            .lines()
+            .count();
+    }
+    if let Some(url) = get("templateUrl").and_then(Expr::as_str) {
+        let resolved = resolve_path(path, url);
+        component.template_lines = index
+            .get(&resolved)
+            .and_then(|f| f.content())
+            .map_or(0, |html| html.lines().count());
+        component.template_url = Some(resolved);
+    }
+    for key in ["styleUrl", "styleUrls"] {
+        if let Some(urls) = get(key) {
+            component.style_urls.extend(
+                urls.items()
+                    .iter()
+                    .filter_map(Expr::as_str)
+                    .map(|url| resolve_path(path, url)),
+            );
+        }
+    }
+
+    for member in &class.members {
+        if member.decorator("Input").is_some() {
+            component.inputs.push(member.name.clone());
+        }
+        if member.decorator("Output").is_some() {
+            component.outputs.push(member.name.clone());
+        }
+        let Some(ExprKind::Call { callee, .. }) = member.value.as_ref().map(|v| &v.kind) else {
+            continue;
+        };
+        match callee.path().as_deref() {
+            Some("input" | "input.required") => component.inputs.push(member.name.clone()),
+            Some("output" | "outputFromObservable") => component.outputs.push(member.name.clone()),
+            // model() は入力と <name>Change 出力の組
+            Some("model" | "model.required") => {
+                component.inputs.push(member.name.clone());
+                component.outputs.push(format!("{}Change", member.name));
+            }
+            _ => {}
+        }
+    }
+
+    component
+}
+
+// inputs: ['name', 'value: alias'] のような指定の名前
+fn names_in(value: Option<&Expr>) -> Vec<String> {
+    value
+        .and_then(Expr::as_array)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|item| match &item.kind {
+            ExprKind::Object(props) => props
+                .iter()
+                .find(|p| p.key == "name")
+                .and_then(|p| p.value.as_str())
+                .map(str::to_string),
+            _ => item
+                .as_str()
+                .map(|s| s.split(':').next().unwrap_or(s).trim().to_string()),
+        })
+        .collect()
+}
+
+fn spread_path(item: &Expr) -> Option<String> {
+    match &item.kind {
+        ExprKind::Spread(inner) => inner.path().map(|p| format!("...{}", p)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    #[test]
+    fn standalone_default_depends_on_version() {
+        let project = TempProject::new(&[(
+            "src/a.component.ts",
+            "@Component({ selector: 'a' })\nexport class A {}\n\
+             @Component({ selector: 'b', imports: [A] })\nexport class B {}\n\
+             @Component({ selector: 'c', standalone: false, imports: [A] })\nexport class C {}\n",
+        )]);
+        let index = project.index();
+        let components = collect_components(&index);
+        let standalone = |angular: Option<Version>| -> Vec<bool> {
+            components
+                .iter()
+                .map(|c| c.is_standalone(angular))
+                .collect()
+        };
+        let version = |major| {
+            Some(Version {
+                major,
+                minor: 0,
+                patch: 0,
+            })
+        };
+
+        assert_eq!(standalone(None), [false, true, false]);
+        assert_eq!(standalone(version(17)), [false, false, false]);
+        assert_eq!(standalone(version(19)), [true, true, false]);
+    }
+}
@@ -0,0 +1,348 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+
+use crate::finding::{Rule, Severity};
+use crate::jsonc;
+
+// プロジェクトのルートにあれば自動で読み込む設定ファイル
+pub const DEFAULT_FILE: &str = "angular-analyzer.json";
+
+// maxImports を指定しない場合の imports の上限
+pub const DEFAULT_MAX_IMPORTS: usize = 20;
+
+// 設定ファイル (コメント付き JSON) の内容
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    // レイヤー定義 (architecture 解析器が使う)
+    pub layers: Vec<Layer>,
+    // Nx のタグによる依存制約。未指定なら .eslintrc.json の設定を使う
+    #[serde(rename = "depConstraints")]
+    pub dep_constraints: Vec<DepConstraint>,
+    // ルール ID ごとの重要度の上書き ("off" で無効)
+    pub rules: BTreeMap<String, RuleLevel>,
+    // OnPush のコンポーネントの割合の下限 (%)。未指定なら割合を報告するだけ
+    #[serde(rename = "minOnPushCoverage")]
+    pub min_on_push_coverage: Option<u32>,
+    // これを下回るとエラーとして報告する割合 (%)。minOnPushCoverage は警告の下限になる
+    #[serde(rename = "minOnPushCoverageError")]
+    pub min_on_push_coverage_error: Option<u32>,
+    // NgModule・standalone コンポーネントの imports の上限 (件)。未指定なら DEFAULT_MAX_IMPORTS
+    #[serde(rename = "maxImports")]
+    pub max_imports: Option<usize>,
+    // ルートのパスごとに必須のガード
+    #[serde(rename = "guardRules")]
+    pub guard_rules: Vec<GuardRule>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    Off,
+    Info,
+    Warning,
+    Error,
+}
+
+// パスの glob で決めるレイヤーと、import してよい他のレイヤー
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Layer {
+    pub name: String,
+    // プロジェクトのルートからの相対パスに対する glob
+    pub paths: Vec<String>,
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(skip)]
+    matcher: GlobSet,
+    // 設定ファイルのルートから解析するディレクトリまでの相対パス (プロジェクト単位の解析で使う)
+    #[serde(skip)]
+    base: String,
+}
+
+// @nx/enforce-module-boundaries と同じ形式の制約
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepConstraint {
+    pub source_tag: String,
+    #[serde(default)]
+    pub only_depend_on_libs_with_tags: Vec<String>,
+    #[serde(default)]
+    pub not_depend_on_libs_with_tags: Vec<String>,
+}
+
+impl DepConstraint {
+    // sourceTag が "*" ならタグの無いプロジェクトにも適用する
+    pub fn applies_to(&self, tags: &[String]) -> bool {
+        self.source_tag == "*" || tags.iter().any(|tag| tag_matches(&self.source_tag, tag))
+    }
+
+    // target_tags を持つプロジェクトへの依存が許されない理由 (許される場合は None)
+    pub fn violation(&self, target_tags: &[String]) -> Option<String> {
+        let has = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|p| target_tags.iter().any(|tag| tag_matches(p, tag)))
+        };
+        if !self.only_depend_on_libs_with_tags.is_empty()
+            && !has(&self.only_depend_on_libs_with_tags)
+        {
+            return Some(format!(
+                "タグ {} のプロジェクトは {} のタグを持つプロジェクトにのみ依存できます",
+                self.source_tag,
+                self.only_depend_on_libs_with_tags.join(", ")
+            ));
+        }
+        if has(&self.not_depend_on_libs_with_tags) {
+            return Some(format!(
+                "タグ {} のプロジェクトは {} のタグを持つプロジェクトに依存できません",
+                self.source_tag,
+                self.not_depend_on_libs_with_tags.join(", ")
+            ));
+        }
+        None
+    }
+}
+
+// path 以下 (path 自身を含む) のルートに guards のすべてが必要
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GuardRule {
+    // "/" から始まる完全なパス ("/admin" は "/admin" と "/admin/..." に一致)
+    pub path: String,
+    pub guards: Vec<String>,
+}
+
+impl GuardRule {
+    pub fn applies_to(&self, full_path: &str) -> bool {
+        let prefix = self.path.trim_end_matches('/');
+        match full_path.strip_prefix(prefix) {
+            Some(rest) => rest.is_empty() || rest.starts_with('/'),
+            None => false,
+        }
+    }
+}
+
+// "*" はすべて、末尾の "*" は前方一致 ("scope:*" など)
+fn tag_matches(pattern: &str, tag: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => tag.starts_with(prefix),
+        None => pattern == tag,
+    }
+}
+
+impl Layer {
+    // relative は解析するディレクトリからの相対パス
+    pub fn matches(&self, relative: &str) -> bool {
+        if self.base.is_empty() {
+            self.matcher.is_match(relative)
+        } else {
+            self.matcher.is_match(format!("{}/{}", self.base, relative))
+        }
+    }
+
+    pub fn allows(&self, other: &Layer) -> bool {
+        self.name == other.name || self.allow.contains(&other.name)
+    }
+}
+
+impl Config {
+    // ワークスペースのルートからの相対パス base のディレクトリを解析するときの設定
+    // (レイヤーの glob はワークスペースのルートからのパスのまま照合する)
+    pub fn for_project(&self, base: &str) -> Self {
+        let mut config = self.clone();
+        for layer in &mut config.layers {
+            layer.base = base.trim_matches('/').to_string();
+        }
+        config
+    }
+
+    pub fn load(path: &str) -> Result<Self> {
+        let source = fs::read_to_string(path)
+            .map_err(|e| Error::new(e.kind(), format!("{}: {}", path, e)))?;
+        let value = jsonc::parse(&source)
+            .ok_or_else(|| invalid(format!("{}: JSON として読み込めません", path)))?;
+        let mut config: Config =
+            serde_json::from_value(value).map_err(|e| invalid(format!("{}: {}", path, e)))?;
+        config
+            .compile()
+            .map_err(|e| invalid(format!("{}: {}", path, e)))?;
+        Ok(config)
+    }
+
+    // プロジェクトのルートに既定の設定ファイルがあれば読み込み、無ければ既定値
+    pub fn discover(project_path: &str) -> Result<Self> {
+        let path = Path::new(project_path).join(DEFAULT_FILE);
+        match path.to_str() {
+            Some(path) if Path::new(path).is_file() => Self::load(path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    // 設定で上書きした重要度 (無効にしたルールは None)
+    pub fn severity(&self, rule_id: &str, default: Severity) -> Option<Severity> {
+        match self.rules.get(rule_id) {
+            None => Some(default),
+            Some(RuleLevel::Off) => None,
+            Some(RuleLevel::Info) => Some(Severity::Info),
+            Some(RuleLevel::Warning) => Some(Severity::Warning),
+            Some(RuleLevel::Error) => Some(Severity::Error),
+        }
+    }
+
+    // rules に書かれたルール ID が known に含まれるか検証する
+    pub fn check_rules(&self, known: &[Rule]) -> Result<()> {
+        match self
+            .rules
+            .keys()
+            .find(|id| !known.iter().any(|r| r.id == *id))
+        {
+            Some(id) => Err(invalid(format!(
+                "rules に未知のルール ID があります: {}",
+                id
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    // 値の範囲、ガードの規則、glob の構文とレイヤー名の参照を検証する
+    fn compile(&mut self) -> std::result::Result<(), String> {
+        for (key, coverage) in [
+            ("minOnPushCoverage", self.min_on_push_coverage),
+            ("minOnPushCoverageError", self.min_on_push_coverage_error),
+        ] {
+            if let Some(coverage) = coverage.filter(|c| *c > 100) {
+                return Err(format!(
+                    "{} は 0 から 100 の範囲で指定してください: {}",
+                    key, coverage
+                ));
+            }
+        }
+        if let (Some(warning), Some(error)) =
+            (self.min_on_push_coverage, self.min_on_push_coverage_error)
+            && error > warning
+        {
+            return Err(format!(
+                "minOnPushCoverageError ({}) は minOnPushCoverage ({}) 以下にしてください",
+                error, warning
+            ));
+        }
+        for rule in &self.guard_rules {
+            if !rule.path.starts_with('/') {
+                return Err(format!(
+                    "guardRules の path は / から始めてください: {}",
+                    rule.path
+                ));
+            }
+            if rule.guards.is_empty() {
+                return Err(format!(
+                    "guardRules の {} に guards がありません",
+                    rule.path
+                ));
+            }
+        }
+        let names: Vec<String> = self.layers.iter().map(|l| l.name.clone()).collect();
+        for layer in &mut self.layers {
+            let mut builder = GlobSetBuilder::new();
+            for pattern in &layer.paths {
+                // "*" はディレクトリの区切りをまたがない ("**" はまたぐ)
+                let glob = GlobBuilder::new(pattern)
+                    .literal_separator(true)
+                    .build()
+                    .map_err(|e| format!("レイヤー {} の paths が不正です: {}", layer.name, e))?;
+                builder.add(glob);
+            }
+            layer.matcher = builder.build().map_err(|e| e.to_string())?;
+
+            if let Some(unknown) = layer.allow.iter().find(|name| !names.contains(name)) {
+                return Err(format!(
+                    "レイヤー {} の allow に未定義のレイヤー {} があります",
+                    layer.name, unknown
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invalid(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    #[test]
+    fn layers_match_from_workspace_root() {
+        let project = TempProject::new(&[(
+            DEFAULT_FILE,
+            r#"{ "layers": [{ "name": "ui", "paths": ["libs/*/ui/**"] }] }"#,
+        )]);
+        let config = Config::discover(&project.root).unwrap();
+        assert!(config.layers[0].matches("libs/shared/ui/src/button.ts"));
+        assert!(!config.layers[0].matches("src/button.ts"));
+
+        let project_config = config.for_project("libs/shared/ui/");
+        assert!(project_config.layers[0].matches("src/button.ts"));
+    }
+
+    #[test]
+    fn on_push_coverage_thresholds() {
+        let load = |source: &str| {
+            let project = TempProject::new(&[(DEFAULT_FILE, source)]);
+            Config::discover(&project.root).map_err(|e| e.to_string())
+        };
+        let config = load(r#"{ "minOnPushCoverage": 80, "minOnPushCoverageError": 50 }"#).unwrap();
+        assert_eq!(
+            (
+                config.min_on_push_coverage,
+                config.min_on_push_coverage_error
+            ),
+            (Some(80), Some(50))
+        );
+        assert!(
+            load(r#"{ "minOnPushCoverage": 101 }"#)
+                .unwrap_err()
+                .contains("minOnPushCoverage は 0 から 100 の範囲で指定してください: 101")
+        );
+        assert!(
+            load(r#"{ "minOnPushCoverage": 50, "minOnPushCoverageError": 80 }"#)
+                .unwrap_err()
+                .contains(
+                    "minOnPushCoverageError (80) は minOnPushCoverage (50) 以下にしてください"
+                )
+        );
+    }
+
+    #[test]
+    fn guard_rules() {
+        let load = |source: &str| {
+            let project = TempProject::new(&[(DEFAULT_FILE, source)]);
+            Config::discover(&project.root).map_err(|e| e.to_string())
+        };
+        let config =
+            load(r#"{ "guardRules": [{ "path": "/admin/", "guards": ["authGuard"] }] }"#).unwrap();
+        let rule = &config.guard_rules[0];
+        assert!(rule.applies_to("/admin"));
+        assert!(rule.applies_to("/admin/users"));
+        assert!(!rule.applies_to("/administration"));
+        assert!(!rule.applies_to("/"));
+
+        assert!(
+            load(r#"{ "guardRules": [{ "path": "admin", "guards": ["authGuard"] }] }"#)
+                .unwrap_err()
+                .contains("guardRules の path は / から始めてください: admin")
+        );
+        assert!(
+            load(r#"{ "guardRules": [{ "path": "/admin", "guards": [] }] }"#)
+                .unwrap_err()
+                .contains("guardRules の /admin に guards がありません")
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        f.write_str(label)
+    }
+}
+
+// 1始まりの行・列 (列は文字単位)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Span {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl Span {
+    // バイトオフセットの範囲から行・列を求める
+    pub fn from_offsets(content: &str, start: usize, end: usize) -> Self {
+        let (start_line, start_column) = line_column(content, start);
+        let (end_line, end_column) = line_column(content, end);
+        Self {
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+        }
+    }
+}
+
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    pub file: String,
+    #[serde(flatten)]
+    pub span: Span,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.file, self.span.start_line, self.span.start_column
+        )
+    }
+}
+
+// 解析器が報告する個々の指摘
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Finding {
+    pub rule_id: String,
+    pub severity: Severity,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+impl Finding {
+    // ファイル位置を持たないプロジェクト全体の指摘
+    pub fn new(rule_id: &str, severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            rule_id: rule_id.to_string(),
+            severity,
+            message: message.into(),
+            location: None,
+            snippet: None,
+        }
+    }
+
+    // content 内のバイト範囲 start..end を指す指摘。該当行をスニペットとして保持する
+    pub fn at(mut self, file: &str, content: &str, start: usize, end: usize) -> Self {
+        let span = Span::from_offsets(content, start, end);
+        self.snippet = content
+            .lines()
+            .nth(span.start_line - 1)
+            .map(|line| line.trim().to_string());
+        self.location = Some(Location {
+            file: file.to_string(),
+            span,
+        });
+        self
+    }
+}
+
+// 解析器が定義するルール。レポートやルール一覧の出力に使う
+#[derive(Debug, Clone, Copy)]
+pub struct Rule {
+    pub id: &'static str,
+    pub severity: Severity,
+    pub description: &'static str,
+}
+
+impl Rule {
+    pub fn finding(&self, message: impl Into<String>) -> Finding {
+        Finding::new(self.id, self.severity, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_from_offsets() {
+        let content = "const a = 1;\n  const 名前 = b;\n";
+        let start = content.find('b').unwrap();
+        let span = Span::from_offsets(content, content.find("const 名前").unwrap(), start + 1);
+        // 列は 1 始まりの文字数 (バイト数ではない)
+        assert_eq!(
+            (
+                span.start_line,
+                span.start_column,
+                span.end_line,
+                span.end_column
+            ),
+            (2, 3, 2, 15)
+        );
+        // 末尾を超えるオフセットは末尾に丸める
+        let end = Span::from_offsets(content, content.len() + 10, content.len() + 10);
+        assert_eq!((end.start_line, end.start_column), (3, 1));
+    }
+
+    #[test]
+    fn finding_at_keeps_location_and_snippet() {
+        let content = "a\n  this.el.innerHTML = html;  \nb\n";
+        let start = content.find("innerHTML").unwrap();
+        let finding = Finding::new("security/inner-html", Severity::Warning, "innerHTML").at(
+            "src/a.ts",
+            content,
+            start,
+            start + "innerHTML".len(),
+        );
+        let location = finding.location.as_ref().unwrap();
+        assert_eq!(location.to_string(), "src/a.ts:2:11");
+        assert_eq!(location.span.end_column, 20);
+        assert_eq!(
+            finding.snippet.as_deref(),
+            Some("this.el.innerHTML = html;")
+        );
+
+        // JSON では位置を平らに出力する
+        let json = serde_json::to_value(&finding).unwrap();
+        assert_eq!(json["location"]["file"], "src/a.ts");
+        assert_eq!(json["location"]["start_line"], 2);
+        assert!(
+            serde_json::to_value(Finding::new("x", Severity::Info, "m"))
+                .unwrap()
+                .get("location")
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+// 解析結果に添える依存関係グラフ。DOT / Mermaid 形式で出力できる
+// ノードと辺は add_node / add_edge でのみ追加し、索引と常に一致させる
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "GraphData")]
+pub struct Graph {
+    pub id: String,
+    pub title: String,
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    // 重複を除くための索引 (ノード ID → 添字、追加済みの辺)
+    #[serde(skip)]
+    node_index: HashMap<String, usize>,
+    #[serde(skip)]
+    edge_set: HashSet<GraphEdge>,
+    // 辺の始点 → edges の添字
+    #[serde(skip)]
+    outgoing: HashMap<String, Vec<usize>>,
+}
+
+// デシリアライズ用 (索引は From で作り直す)
+#[derive(Deserialize)]
+struct GraphData {
+    id: String,
+    title: String,
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+}
+
+impl From<GraphData> for Graph {
+    fn from(data: GraphData) -> Self {
+        let mut graph = Graph::new(&data.id, &data.title);
+        for node in data.nodes {
+            graph.add_node(&node.id, &node.label, &node.kind);
+        }
+        for edge in data.edges {
+            graph.add_edge(&edge.from, &edge.to, &edge.label);
+        }
+        graph
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphNode {
+    pub id: String,
+    pub label: String,
+    // 描画の形を決める種別 (例: "ngmodule", "component", "external", "lazy")
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub label: String,
+}
+
+impl Graph {
+    pub fn new(id: &str, title: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            title: title.to_string(),
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            node_index: HashMap::new(),
+            edge_set: HashSet::new(),
+            outgoing: HashMap::new(),
+        }
+    }
+
+    pub fn nodes(&self) -> &[GraphNode] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &[GraphEdge] {
+        &self.edges
+    }
+
+    // 同じ ID のノードは最初に追加したものを残す
+    pub fn add_node(&mut self, id: &str, label: &str, kind: &str) {
+        if !self.node_index.contains_key(id) {
+            self.node_index.insert(id.to_string(), self.nodes.len());
+            self.nodes.push(GraphNode {
+                id: id.to_string(),
+                label: label.to_string(),
+                kind: kind.to_string(),
+            });
+        }
+    }
+
+    // 同じ向き・同じラベルの辺は 1 本にまとめる
+    pub fn add_edge(&mut self, from: &str, to: &str, label: &str) {
+        let edge = GraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            label: label.to_string(),
+        };
+        if self.edge_set.insert(edge.clone()) {
+            self.outgoing
+                .entry(edge.from.clone())
+                .or_default()
+                .push(self.edges.len());
+            self.edges.push(edge);
+        }
+    }
+
+    // from から出る辺 (追加した順)
+    pub fn edges_from<'a>(&'a self, from: &str) -> impl Iterator<Item = &'a GraphEdge> {
+        self.outgoing
+            .get(from)
+            .into_iter()
+            .flatten()
+            .map(|&i| &self.edges[i])
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "digraph {} {{", dot_quote(&self.id)).unwrap();
+        writeln!(out, "  label={};", dot_quote(&self.title)).unwrap();
+        writeln!(out, "  rankdir=LR;").unwrap();
+        writeln!(out, "  node [shape=box, fontname=\"sans-serif\"];").unwrap();
+        for node in &self.nodes {
+            let style = match node.kind.as_str() {
+                "ngmodule" => ", shape=box3d",
+                "component" => ", shape=ellipse",
+                "external" => ", style=dashed",
+                "application" => ", shape=doubleoctagon",
+                "lazy" => ", style=\"dashed,rounded\"",
+                "redirect" => ", shape=cds",
+                _ => "",
+            };
+            writeln!(
+                out,
+                "  {} [label={}{}];",
+                dot_quote(&node.id),
+                dot_quote(&node.label),
+                style
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            if edge.label.is_empty() {
+                writeln!(
+                    out,
+                    "  {} -> {};",
+                    dot_quote(&edge.from),
+                    dot_quote(&edge.to)
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "  {} -> {} [label={}];",
+                    dot_quote(&edge.from),
+                    dot_quote(&edge.to),
+                    dot_quote(&edge.label)
+                )
+                .unwrap();
+            }
+        }
+        writeln!(out, "}}").unwrap();
+        out
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "flowchart LR").unwrap();
+        // Mermaid のノード ID に使えない文字を含むため連番の ID を振る
+        let positions: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.id.as_str(), i))
+            .collect();
+        let id_of = |id: &str| {
+            positions
+                .get(id)
+                .map_or_else(|| mermaid_id(id), |i| format!("n{}", i))
+        };
+        for (i, node) in self.nodes.iter().enumerate() {
+            let label = mermaid_label(&node.label);
+            let shape = match node.kind.as_str() {
+                "ngmodule" => format!("[[{}]]", label),
+                "component" => format!("({})", label),
+                "external" => format!("[/{}/]", label),
+                "application" => format!("{{{{{}}}}}", label),
+                "lazy" => format!("([{}])", label),
+                "redirect" => format!(">{}]", label),
+                _ => format!("[{}]", label),
+            };
+            writeln!(out, "  n{}{}", i, shape).unwrap();
+        }
+        for edge in &self.edges {
+            if edge.label.is_empty() {
+                writeln!(out, "  {} --> {}", id_of(&edge.from), id_of(&edge.to)).unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "  {} -->|{}| {}",
+                    id_of(&edge.from),
+                    mermaid_label(&edge.label),
+                    id_of(&edge.to)
+                )
+                .unwrap();
+            }
+        }
+        out
+    }
+}
+
+fn dot_quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn mermaid_label(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "#quot;"))
+}
+
+fn mermaid_id(id: &str) -> String {
+    id.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deduplicates_nodes_and_edges() {
+        let mut graph = Graph::new("g", "テスト");
+        graph.add_node("a/x.ts", "x", "file");
+        graph.add_node("b", "b", "file");
+        graph.add_node("a/x.ts", "別名", "component");
+        graph.add_edge("a/x.ts", "b", "");
+        graph.add_edge("a/x.ts", "b", "");
+        graph.add_edge("a/x.ts", "b", "exports");
+        graph.add_edge("b", "c", "");
+
+        assert_eq!(graph.nodes().len(), 2);
+        assert_eq!(graph.nodes()[0].label, "x");
+        assert_eq!(graph.edges().len(), 3);
+        let labels: Vec<&str> = graph
+            .edges_from("a/x.ts")
+            .map(|e| e.label.as_str())
+            .collect();
+        assert_eq!(labels, ["", "exports"]);
+        assert_eq!(graph.edges_from("c").count(), 0);
+
+        // 未登録のノード ID は記号を置き換えて使う
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.contains("  n0 --> n1\n"));
+        assert!(mermaid.contains("  n0 -->|\"exports\"| n1\n"));
+        assert!(mermaid.contains("  n1 --> c\n"));
+    }
+
+    #[test]
+    fn index_follows_deserialized_graph() {
+        let mut graph = Graph::new("g", "テスト");
+        graph.add_node("a", "a", "file");
+        graph.add_edge("a", "b", "");
+        let mut graph: Graph =
+            serde_json::from_str(&serde_json::to_string(&graph).unwrap()).unwrap();
+        graph.add_node("a", "a", "file");
+        graph.add_edge("a", "b", "");
+        assert_eq!((graph.nodes().len(), graph.edges().len()), (1, 1));
+        assert_eq!(graph.edges_from("a").count(), 1);
+    }
+}
@@ -0,0 +1,79 @@
+use rayon::prelude::*;
+
+use crate::index::{FileKind, ProjectIndex};
+use crate::ts::{ImportKind, TextRange};
+
+// プロジェクト内のファイルに解決できた import
+#[derive(Debug, Clone)]
+pub struct Import {
+    // import 先の添字 (ImportGraph::files)
+    pub to: usize,
+    pub specifier: String,
+    pub range: TextRange,
+}
+
+// TypeScript ファイルを添字で表した import の有向グラフ
+#[derive(Debug, Default)]
+pub struct ImportGraph {
+    // パス順に並んだ TypeScript ファイル
+    pub files: Vec<String>,
+    // 添字ごとの import 先 (同じファイルへの import は最初の 1 件だけ)
+    pub edges: Vec<Vec<Import>>,
+    // 相対パスかパスエイリアスに一致するのに解決できなかった import の数
+    pub unresolved: usize,
+}
+
+impl ImportGraph {
+    pub fn position(&self, path: &str) -> Option<usize> {
+        self.files.binary_search_by(|f| f.as_str().cmp(path)).ok()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+}
+
+// 型のみの import と動的 import は実行時の初期化順に影響しないため除く
+pub fn collect_imports(index: &ProjectIndex) -> ImportGraph {
+    let mut graph = ImportGraph {
+        files: index.files(FileKind::Ts).map(|f| f.path.clone()).collect(),
+        ..Default::default()
+    };
+    // import の解決はファイルごとに並列に行う (par_files は files と同じ順序)
+    let resolved: Vec<(Vec<Import>, usize)> = index
+        .par_files(FileKind::Ts)
+        .map(|file| {
+            let mut edges: Vec<Import> = Vec::new();
+            let mut unresolved = 0;
+            let Some(module) = file.module() else {
+                return (edges, unresolved);
+            };
+            for import in &module.imports {
+                if import.type_only || import.kind == ImportKind::Dynamic {
+                    continue;
+                }
+                match index.resolve_import(&file.path, &import.source) {
+                    Some(target) => {
+                        if let Some(to) = graph.position(&target.path)
+                            && !edges.iter().any(|e| e.to == to)
+                        {
+                            edges.push(Import {
+                                to,
+                                specifier: import.source.clone(),
+                                range: import.range,
+                            });
+                        }
+                    }
+                    None if index.is_local_import(&file.path, &import.source) => unresolved += 1,
+                    None => {}
+                }
+            }
+            (edges, unresolved)
+        })
+        .collect();
+    for (edges, unresolved) in resolved {
+        graph.edges.push(edges);
+        graph.unresolved += unresolved;
+    }
+    graph
+}
@@ -0,0 +1,544 @@
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Result;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+use crate::component::{self, Component};
+use crate::imports::{self, ImportGraph};
+use crate::packages::{self, Packages};
+use crate::routes::{self, Route};
+use crate::template::{self, ComponentTemplate};
+use crate::ts::{self, Module};
+use crate::tsconfig::{self, TsConfig};
+use crate::workspace::{self, Workspace};
+
+// import の解決に使う tsconfig のファイル名 (同じディレクトリでは先のものを優先)
+const TSCONFIG_NAMES: &[&str] = &["tsconfig.json", "tsconfig.base.json"];
+
+// インデックスが分類するファイル種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    Ts,
+    // *.component.ts (Ts にも含まれる)
+    ComponentTs,
+    Html,
+    Scss,
+    Css,
+    Json,
+}
+
+impl FileKind {
+    pub const ALL: [FileKind; 6] = [
+        FileKind::Ts,
+        FileKind::ComponentTs,
+        FileKind::Html,
+        FileKind::Scss,
+        FileKind::Css,
+        FileKind::Json,
+    ];
+
+    pub fn suffix(self) -> &'static str {
+        match self {
+            FileKind::Ts => ".ts",
+            FileKind::ComponentTs => ".component.ts",
+            FileKind::Html => ".html",
+            FileKind::Scss => ".scss",
+            FileKind::Css => ".css",
+            FileKind::Json => ".json",
+        }
+    }
+}
+
+// パスと、初回アクセス時に読み込まれる内容・構文解析結果
+#[derive(Debug)]
+pub struct SourceFile {
+    pub path: String,
+    content: OnceLock<Option<String>>,
+    module: OnceLock<Option<Module>>,
+}
+
+impl SourceFile {
+    fn new(path: String) -> Self {
+        Self {
+            path,
+            content: OnceLock::new(),
+            module: OnceLock::new(),
+        }
+    }
+
+    // 読み込みに失敗したファイルは None (従来どおりスキップ対象)
+    pub fn content(&self) -> Option<&str> {
+        self.content
+            .get_or_init(|| fs::read_to_string(&self.path).ok())
+            .as_deref()
+    }
+
+    // TypeScript ファイルの構文解析結果 (初回アクセス時に解析してキャッシュ)
+    // 読み込めない、または構文エラーで解析できないファイルは None
+    pub fn module(&self) -> Option<&Module> {
+        self.module
+            .get_or_init(|| {
+                if !self.path.ends_with(FileKind::Ts.suffix()) {
+                    return None;
+                }
+                ts::parse_module(&self.path, self.content()?)
+            })
+            .as_ref()
+    }
+}
+
+// 1回のディレクトリ走査で構築するプロジェクトのファイル一覧
+#[derive(Debug)]
+pub struct ProjectIndex {
+    pub root: String,
+    // 部分インデックス (subset) とは読み込み・構文解析の結果を共有する
+    // subset でも元の全ファイルを持ち (get で引ける)、by_kind にはルート以下のファイルだけを入れる
+    files: Vec<Arc<SourceFile>>,
+    by_kind: HashMap<FileKind, Vec<usize>>,
+    templates: OnceLock<Vec<ComponentTemplate>>,
+    components: OnceLock<Vec<Component>>,
+    tsconfigs: OnceLock<Vec<TsConfig>>,
+    imports: OnceLock<Arc<ImportGraph>>,
+    // subset の場合、元のインデックスの import グラフ (ルートの外への import を含む)
+    workspace_imports: Option<Arc<ImportGraph>>,
+    workspace: OnceLock<Option<Workspace>>,
+    packages: OnceLock<Option<Packages>>,
+    routes: OnceLock<Vec<Route>>,
+}
+
+impl ProjectIndex {
+    pub fn build(root: &str) -> Result<Self> {
+        let mut paths = Vec::new();
+        collect_files(Path::new(root), &mut paths)?;
+        paths.sort();
+        let files = paths
+            .into_iter()
+            .map(|path| Arc::new(SourceFile::new(path)))
+            .collect();
+        Ok(Self::with_files(root, files))
+    }
+
+    // root 以下のファイルだけのインデックス (ディレクトリを再走査せず、読み込み済みの内容を使う)
+    // ワークスペース定義・import グラフは元のインデックスのものを引き継ぎ、
+    // package.json は root に無ければ元のインデックスのものを使う
+    pub fn subset(&self, root: &str) -> Self {
+        let root = root.trim_end_matches('/');
+        let mut subset = Self::with_files(root, self.files.clone());
+        subset.workspace = OnceLock::from(self.workspace().cloned());
+        subset.workspace_imports = Some(
+            self.workspace_imports
+                .clone()
+                .unwrap_or_else(|| self.shared_imports().clone()),
+        );
+        let packages = packages::load(&subset).or_else(|| self.packages().cloned());
+        subset.packages = OnceLock::from(packages);
+        subset
+    }
+
+    fn with_files(root: &str, files: Vec<Arc<SourceFile>>) -> Self {
+        let mut by_kind: HashMap<FileKind, Vec<usize>> = HashMap::new();
+        for (i, file) in files.iter().enumerate() {
+            if !is_under(&file.path, root) {
+                continue;
+            }
+            for kind in FileKind::ALL {
+                if file.path.ends_with(kind.suffix()) {
+                    by_kind.entry(kind).or_default().push(i);
+                }
+            }
+        }
+
+        Self {
+            root: root.to_string(),
+            files,
+            by_kind,
+            templates: OnceLock::new(),
+            components: OnceLock::new(),
+            tsconfigs: OnceLock::new(),
+            imports: OnceLock::new(),
+            workspace_imports: None,
+            workspace: OnceLock::new(),
+            packages: OnceLock::new(),
+            routes: OnceLock::new(),
+        }
+    }
+
+    // root 以下のファイルか (subset の外のファイルも get では引ける)
+    pub fn contains(&self, path: &str) -> bool {
+        is_under(path, &self.root)
+    }
+
+    pub fn files(&self, kind: FileKind) -> impl Iterator<Item = &SourceFile> {
+        self.by_kind
+            .get(&kind)
+            .into_iter()
+            .flatten()
+            .map(|&i| &*self.files[i])
+    }
+
+    // 分類済みファイルの読み込みと TypeScript の構文解析を並列に済ませておく
+    pub fn preload(&self) {
+        let mut indices: Vec<usize> = self.by_kind.values().flatten().copied().collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices.par_iter().for_each(|&i| {
+            self.files[i].content();
+            self.files[i].module();
+        });
+        self.tsconfigs();
+        self.templates();
+        self.components();
+        self.imports();
+    }
+
+    // 全コンポーネントのテンプレート (初回アクセス時に解析してキャッシュ)
+    pub fn templates(&self) -> &[ComponentTemplate] {
+        self.templates
+            .get_or_init(|| template::collect_templates(self))
+    }
+
+    // 全コンポーネントの情報 (初回アクセス時に抽出してキャッシュ)
+    pub fn components(&self) -> &[Component] {
+        self.components
+            .get_or_init(|| component::collect_components(self))
+    }
+
+    // 解決済みの import グラフ (初回アクセス時に構築してキャッシュ)
+    pub fn imports(&self) -> &ImportGraph {
+        self.shared_imports()
+    }
+
+    fn shared_imports(&self) -> &Arc<ImportGraph> {
+        self.imports
+            .get_or_init(|| Arc::new(imports::collect_imports(self)))
+    }
+
+    // ワークスペース全体の import グラフ (subset でなければ imports と同じ)
+    // subset のファイルから外のプロジェクトへの import を調べるのに使う
+    pub fn workspace_imports(&self) -> &ImportGraph {
+        self.workspace_imports
+            .as_deref()
+            .unwrap_or_else(|| self.imports())
+    }
+
+    // モノレポのワークスペース定義 (初回アクセス時に読み込んでキャッシュ)
+    pub fn workspace(&self) -> Option<&Workspace> {
+        self.workspace
+            .get_or_init(|| workspace::discover(self))
+            .as_ref()
+    }
+
+    // Routes 配列から組み立てたルートツリー (初回アクセス時に解析してキャッシュ)
+    pub fn routes(&self) -> &[Route] {
+        self.routes.get_or_init(|| routes::collect_routes(self))
+    }
+
+    // package.json の依存とロックファイルのバージョン (初回アクセス時に読み込んでキャッシュ)
+    pub fn packages(&self) -> Option<&Packages> {
+        self.packages.get_or_init(|| packages::load(self)).as_ref()
+    }
+
+    // プロジェクト内の tsconfig (extends を反映済み、初回アクセス時に読み込んでキャッシュ)
+    // ルートより上にある最寄りの tsconfig も含める (モノレポ内のプロジェクトだけを解析する場合)
+    pub fn tsconfigs(&self) -> &[TsConfig] {
+        self.tsconfigs.get_or_init(|| {
+            let mut configs: Vec<TsConfig> = self
+                .files(FileKind::Json)
+                .filter(|f| {
+                    let name = f.path.rsplit('/').next().unwrap_or(&f.path);
+                    TSCONFIG_NAMES.contains(&name)
+                })
+                .filter_map(|f| TsConfig::load(&f.path))
+                .collect();
+
+            let mut dir = self.root.trim_end_matches('/');
+            while let Some((parent, _)) = dir.rsplit_once('/')
+                && !parent.is_empty()
+            {
+                let outer = TSCONFIG_NAMES
+                    .iter()
+                    .map(|name| format!("{}/{}", parent, name))
+                    .find(|path| Path::new(path).is_file());
+                if let Some(config) = outer.and_then(|path| TsConfig::load(&path)) {
+                    configs.push(config);
+                    break;
+                }
+                dir = parent;
+            }
+            configs
+        })
+    }
+
+    // from_file から親ディレクトリをたどって最初に見つかる tsconfig
+    pub fn tsconfig_for(&self, from_file: &str) -> Option<&TsConfig> {
+        let configs = self.tsconfigs();
+        let mut dir = from_file;
+        while let Some((parent, _)) = dir.rsplit_once('/') {
+            for name in TSCONFIG_NAMES {
+                let path = format!("{}/{}", parent, name);
+                if let Some(config) = configs.iter().find(|c| c.path == path) {
+                    return Some(config);
+                }
+            }
+            dir = parent;
+        }
+        None
+    }
+
+    // ルートからの相対パス (表示用)
+    pub fn relative<'a>(&self, path: &'a str) -> &'a str {
+        match path.strip_prefix(self.root.trim_end_matches('/')) {
+            // "/a/b" と "/a/bc/x.ts" のような前方一致は root 以下とみなさない
+            Some(rest) if rest.is_empty() || rest.starts_with('/') => rest.trim_start_matches('/'),
+            _ => path,
+        }
+    }
+
+    // files と同じ順序の並列イテレータ (collect すればファイル順に並ぶ)
+    pub fn par_files(&self, kind: FileKind) -> impl IndexedParallelIterator<Item = &SourceFile> {
+        self.by_kind
+            .get(&kind)
+            .map_or(&[][..], Vec::as_slice)
+            .par_iter()
+            .map(|&i| &*self.files[i])
+    }
+
+    pub fn count(&self, kind: FileKind) -> usize {
+        self.by_kind.get(&kind).map_or(0, Vec::len)
+    }
+
+    pub fn all_files(&self) -> impl Iterator<Item = &SourceFile> {
+        self.files
+            .iter()
+            .map(|f| &**f)
+            .filter(|f| self.contains(&f.path))
+    }
+
+    pub fn get(&self, path: &str) -> Option<&SourceFile> {
+        self.files
+            .binary_search_by(|f| f.path.as_str().cmp(path))
+            .ok()
+            .map(|i| &*self.files[i])
+    }
+
+    // import の指定子をプロジェクト内の TypeScript ファイルに解決する
+    // 相対パスのほか、最寄りの tsconfig の paths エイリアスと baseUrl からの指定を解決する
+    pub fn resolve_import(&self, from_file: &str, specifier: &str) -> Option<&SourceFile> {
+        if is_relative(specifier) {
+            return self.resolve_module(&resolve_path(from_file, specifier));
+        }
+        let config = self.tsconfig_for(from_file)?;
+        if let Some(targets) = config.alias_targets(specifier) {
+            return targets
+                .iter()
+                .find_map(|target| self.resolve_module(target));
+        }
+        let base_url = config.base_url.as_deref()?;
+        self.resolve_module(&tsconfig::join(base_url, specifier))
+    }
+
+    // プロジェクト内のファイルを指すはずの import か
+    // (相対パスか、置換先がルート以下になる paths エイリアスに一致する)
+    pub fn is_local_import(&self, from_file: &str, specifier: &str) -> bool {
+        let root = self.root.trim_end_matches('/');
+        is_relative(specifier)
+            || self
+                .tsconfig_for(from_file)
+                .and_then(|c| c.alias_targets(specifier))
+                .is_some_and(|targets| targets.iter().any(|t| t.starts_with(root)))
+    }
+
+    // 拡張子の省略と index.ts (バレル) を補ってファイルを探す
+    fn resolve_module(&self, base: &str) -> Option<&SourceFile> {
+        let ts = FileKind::Ts.suffix();
+        let mut candidates = Vec::new();
+        if base.ends_with(ts) {
+            candidates.push(base.to_string());
+        }
+        // ESM 形式の "./foo.js" は foo.ts を指す
+        if let Some(stem) = base.strip_suffix(".js") {
+            candidates.push(format!("{}{}", stem, ts));
+        }
+        candidates.push(format!("{}{}", base, ts));
+        candidates.push(format!("{}/index{}", base, ts));
+        candidates.iter().find_map(|path| self.get(path))
+    }
+}
+
+// path が dir 以下にあるか (dir と同じ接頭辞を持つ別のディレクトリは含めない)
+pub fn is_under(path: &str, dir: &str) -> bool {
+    path.strip_prefix(dir.trim_end_matches('/'))
+        .is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn is_relative(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+}
+
+// from_file からの相対パスを解決する ("./" や "../" を正規化)
+pub fn resolve_path(from_file: &str, relative: &str) -> String {
+    let dir = from_file.rsplit_once('/').map_or("", |(dir, _)| dir);
+    let joined = if relative.starts_with('/') {
+        relative.to_string()
+    } else {
+        format!("{}/{}", dir, relative)
+    };
+
+    let mut parts: Vec<&str> = Vec::new();
+    for (i, part) in joined.split('/').enumerate() {
+        match part {
+            // 先頭の "" (絶対パス) と "." (相対ルート) は残す
+            "" | "." if i == 0 => parts.push(part),
+            "" | "." => {}
+            ".." if parts.last().is_some_and(|p| !matches!(*p, "" | "." | "..")) => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<String>) -> Result<()> {
+    if dir.is_dir() {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.is_dir() {
+                let dir_name = path.file_name().unwrap().to_str().unwrap();
+                if !dir_name.starts_with('.') && dir_name != "node_modules" {
+                    collect_files(&path, files)?;
+                }
+            } else if path.extension().is_some() {
+                files.push(path.to_str().unwrap().to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    #[test]
+    fn resolve_path_normalizes_segments() {
+        assert_eq!(resolve_path("/a/b/c.ts", "./d"), "/a/b/d");
+        assert_eq!(resolve_path("/a/b/c.ts", "../../x/./y"), "/x/y");
+        assert_eq!(resolve_path("/a/b/c.ts", "/abs/z"), "/abs/z");
+        assert_eq!(resolve_path("a/c.ts", "../../d"), "../d");
+    }
+
+    #[test]
+    fn resolve_import_relative_alias_and_base_url() {
+        let project = TempProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "baseUrl": "src", "paths": { "@core/*": ["app/core/*"] } } }"#,
+            ),
+            ("src/main.ts", ""),
+            ("src/app/core/auth.service.ts", ""),
+            ("src/app/shared/index.ts", ""),
+            ("src/app/esm.ts", ""),
+        ]);
+        let index = project.index();
+        let main = project.path("src/main.ts");
+        let resolved = |specifier: &str| {
+            index
+                .resolve_import(&main, specifier)
+                .map(|f| index.relative(&f.path).to_string())
+        };
+        assert_eq!(
+            resolved("@core/auth.service").as_deref(),
+            Some("src/app/core/auth.service.ts")
+        );
+        assert_eq!(
+            resolved("./app/shared").as_deref(),
+            Some("src/app/shared/index.ts")
+        );
+        assert_eq!(resolved("./app/esm.js").as_deref(), Some("src/app/esm.ts"));
+        assert_eq!(
+            resolved("app/shared").as_deref(),
+            Some("src/app/shared/index.ts")
+        );
+        assert_eq!(resolved("@core/missing"), None);
+        assert_eq!(resolved("@angular/core"), None);
+
+        assert!(index.is_local_import(&main, "@core/anything"));
+        assert!(!index.is_local_import(&main, "rxjs"));
+    }
+
+    #[test]
+    fn nearest_tsconfig_wins() {
+        let project = TempProject::new(&[
+            (
+                "tsconfig.base.json",
+                r#"{ "compilerOptions": { "paths": { "@lib/*": ["libs/*"] } } }"#,
+            ),
+            (
+                "apps/a/tsconfig.json",
+                r#"{ "extends": "../../tsconfig.base.json" }"#,
+            ),
+            ("apps/a/main.ts", ""),
+            ("libs/ui/index.ts", ""),
+        ]);
+        let index = project.index();
+        let main = project.path("apps/a/main.ts");
+        assert_eq!(
+            index.tsconfig_for(&main).map(|c| c.path.clone()),
+            Some(project.path("apps/a/tsconfig.json"))
+        );
+        assert_eq!(
+            index
+                .resolve_import(&main, "@lib/ui")
+                .map(|f| f.path.clone()),
+            Some(project.path("libs/ui/index.ts"))
+        );
+    }
+
+    #[test]
+    fn subset_shares_loaded_files() {
+        let project = TempProject::new(&[
+            ("apps/a/src/main.ts", "import { x } from './x';"),
+            ("apps/a/src/x.ts", "export const x = 1;"),
+            ("apps/ab/src/main.ts", ""),
+            ("apps/a.json", "{}"),
+        ]);
+        let index = project.index();
+        let main = project.path("apps/a/src/main.ts");
+        assert!(index.get(&main).unwrap().module().is_some());
+
+        let subset = index.subset(&format!("{}/", project.path("apps/a")));
+        assert_eq!(subset.root, project.path("apps/a"));
+        let paths: Vec<&str> = subset.all_files().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, [main.as_str(), &project.path("apps/a/src/x.ts")]);
+        assert_eq!(subset.count(FileKind::Ts), 2);
+        assert_eq!(subset.count(FileKind::Json), 0);
+        // 読み込み済みの構文解析結果をそのまま使う
+        assert!(std::ptr::eq(
+            subset.get(&main).unwrap().module().unwrap(),
+            index.get(&main).unwrap().module().unwrap()
+        ));
+        assert_eq!(subset.relative(&main), "src/main.ts");
+        // ルートの外のファイルは一覧に含めないが、get では引ける
+        let outside = project.path("apps/ab/src/main.ts");
+        assert!(!subset.contains(&outside));
+        assert!(subset.get(&outside).is_some());
+        assert_eq!(subset.relative(&outside), outside);
+    }
+
+    #[test]
+    fn component_files_are_also_ts() {
+        let project = TempProject::new(&[("src/app.component.ts", ""), ("src/app.service.ts", "")]);
+        let index = project.index();
+        assert_eq!(index.count(FileKind::Ts), 2);
+        assert_eq!(index.count(FileKind::ComponentTs), 1);
+        assert_eq!(index.all_files().count(), 2);
+    }
+}
@@ -0,0 +1,193 @@
+use serde_json::Value;
+use std::ops::Range;
+
+// コメントと末尾カンマを許す JSON (tsconfig.json や angular.json で使われる形式) を読む
+pub fn parse(source: &str) -> Option<Value> {
+    serde_json::from_str(&strip(source)).ok()
+}
+
+// 文字列の外にあるコメントと、閉じ括弧直前のカンマを取り除く
+fn strip(source: &str) -> String {
+    let bytes = source.as_bytes();
+    let mut out = String::with_capacity(source.len());
+    let mut i = 0;
+    // 直前に出力したカンマの位置 (その後に空白しか無い間だけ保持する)
+    let mut pending_comma: Option<usize> = None;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i = (i + 1).min(bytes.len());
+                out.push_str(&source[start..i]);
+                pending_comma = None;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i = (i + 2).min(bytes.len());
+            }
+            b',' => {
+                pending_comma = Some(out.len());
+                out.push(',');
+                i += 1;
+            }
+            b'}' | b']' => {
+                if let Some(comma) = pending_comma.take() {
+                    out.replace_range(comma..comma + 1, " ");
+                }
+                out.push(bytes[i] as char);
+                i += 1;
+            }
+            _ => {
+                // 多バイト文字は文字単位で写す
+                let ch = source[i..].chars().next().unwrap();
+                if !ch.is_whitespace() {
+                    pending_comma = None;
+                }
+                out.push(ch);
+                i += ch.len_utf8();
+            }
+        }
+    }
+    out
+}
+
+// キーの並び (例: ["projects", "app"]) で指したオブジェクトのキーの位置 (引用符を含む)
+pub fn key_range(source: &str, path: &[&str]) -> Option<Range<usize>> {
+    let bytes = source.as_bytes();
+    // 開いている括弧ごとに、オブジェクトかどうかと親でのキー
+    let mut open: Vec<(bool, Option<&str>)> = Vec::new();
+    // 直前の文字列 (次に ':' が来ればキー)
+    let mut pending: Option<Range<usize>> = None;
+    let mut key: Option<&str> = None;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i = (i + 1).min(bytes.len());
+                pending = matches!(open.last(), Some((true, _))).then_some(start..i);
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i = (i + 2).min(bytes.len());
+                continue;
+            }
+            b':' => {
+                if let Some(range) = pending.take() {
+                    let name = source
+                        .get(range.start + 1..range.end - 1)
+                        .unwrap_or_default();
+                    let parents = open.iter().skip(1).map(|(_, k)| *k);
+                    if open.len() == path.len()
+                        && name == path[path.len() - 1]
+                        && parents.eq(path[..path.len() - 1].iter().map(|k| Some(*k)))
+                    {
+                        return Some(range);
+                    }
+                    key = Some(name);
+                }
+            }
+            b'{' | b'[' => open.push((bytes[i] == b'{', key.take())),
+            b'}' | b']' => {
+                open.pop();
+                key = None;
+            }
+            b',' => key = None,
+            _ => {}
+        }
+        if !bytes[i].is_ascii_whitespace() && bytes[i] != b':' {
+            pending = None;
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn comments_and_trailing_commas() {
+        let source = r#"{
+            // 行コメント
+            "a": [1, 2, ], /* ブロック
+            コメント */ "b": { "c": "x", },
+        }"#;
+        assert_eq!(
+            parse(source),
+            Some(json!({ "a": [1, 2], "b": { "c": "x" } }))
+        );
+    }
+
+    #[test]
+    fn comment_markers_and_commas_inside_strings() {
+        let source = r#"{ "url": "http://x/*y*/", "s": "a,]", "q": "\"//", "日本": "語" }"#;
+        assert_eq!(
+            parse(source),
+            Some(json!({ "url": "http://x/*y*/", "s": "a,]", "q": "\"//", "日本": "語" }))
+        );
+    }
+
+    #[test]
+    fn invalid_json() {
+        assert_eq!(parse("{ \"a\": }"), None);
+        assert_eq!(parse("{ \"a\": 1 /* unterminated"), None);
+    }
+
+    #[test]
+    fn key_range_follows_nesting() {
+        let source = r#"{
+            "name": "projects",
+            // "app": 0
+            "projects": {
+                "lib": { "root": "app", "tags": ["app"] },
+                "app": { "architect": { "build": {} } },
+            },
+            "app": 1
+        }"#;
+        let text = |path: &[&str]| key_range(source, path).map(|r| &source[r]);
+        let app = key_range(source, &["projects", "app"]).unwrap();
+        assert_eq!(&source[app.clone()], "\"app\"");
+        assert!(source[..app.start].contains("\"lib\""));
+        assert_eq!(
+            text(&["projects", "app", "architect", "build"]),
+            Some("\"build\"")
+        );
+        assert_eq!(
+            key_range(source, &["app"]).map(|r| r.start),
+            source.rfind("\"app\"")
+        );
+        assert_eq!(text(&["projects", "missing"]), None);
+        assert_eq!(text(&["root"]), None);
+    }
+}
@@ -0,0 +1,35 @@
+pub mod analyzer;
+pub mod analyzers;
+pub mod component;
+pub mod config;
+pub mod finding;
+pub mod graph;
+pub mod imports;
+pub mod index;
+pub mod jsonc;
+pub mod packages;
+pub mod report;
+pub mod routes;
+pub mod table;
+pub mod template;
+#[cfg(test)]
+pub(crate) mod testing;
+pub mod time;
+pub mod ts;
+pub mod tsconfig;
+pub mod workspace;
+
+pub use analyzer::{
+    AnalysisResult, Analyzer, AnalyzerRegistry, FrontendAnalyzer, ProjectContext, Status,
+};
+pub use component::Component;
+pub use config::Config;
+pub use finding::{Finding, Location, Rule, Severity, Span};
+pub use graph::Graph;
+pub use imports::ImportGraph;
+pub use index::{FileKind, ProjectIndex, SourceFile};
+pub use packages::Packages;
+pub use routes::Route;
+pub use table::Table;
+pub use tsconfig::TsConfig;
+pub use workspace::{Project, Workspace};
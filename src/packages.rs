@@ -0,0 +1,462 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::index::ProjectIndex;
+use crate::jsonc;
+
+// package.json の依存区分
+const SECTIONS: &[(&str, bool)] = &[
+    ("dependencies", false),
+    ("devDependencies", true),
+    ("optionalDependencies", false),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockKind {
+    Npm,
+    Yarn,
+    Pnpm,
+}
+
+impl LockKind {
+    pub const ALL: [(LockKind, &'static str); 3] = [
+        (LockKind::Npm, "package-lock.json"),
+        (LockKind::Yarn, "yarn.lock"),
+        (LockKind::Pnpm, "pnpm-lock.yaml"),
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LockKind::Npm => "npm",
+            LockKind::Yarn => "yarn",
+            LockKind::Pnpm => "pnpm",
+        }
+    }
+}
+
+// package.json の 1 依存と、ロックファイルで確定したバージョン
+#[derive(Debug, Clone)]
+pub struct Dependency {
+    pub name: String,
+    // package.json に書かれた範囲 ("^17.3.0" など)
+    pub range: String,
+    pub installed: Option<String>,
+    pub dev: bool,
+}
+
+impl Dependency {
+    // ロックファイルで確定したバージョン (ロックファイルに無ければ不明)
+    pub fn installed_version(&self) -> Option<Version> {
+        self.installed.as_deref().and_then(Version::parse)
+    }
+
+    // ロックファイルのバージョン。無ければ範囲に書かれた最小のバージョン
+    // (API の有無などメジャーバージョンでの判定用。実際のバージョンの比較には installed_version を使う)
+    pub fn version(&self) -> Option<Version> {
+        self.installed
+            .as_deref()
+            .and_then(Version::parse)
+            .or_else(|| Version::parse(&self.range))
+    }
+}
+
+// package.json とロックファイルの内容
+#[derive(Debug, Clone)]
+pub struct Packages {
+    pub manifest: String,
+    pub lock: Option<(LockKind, String)>,
+    // 名前順
+    pub dependencies: Vec<Dependency>,
+}
+
+impl Packages {
+    pub fn get(&self, name: &str) -> Option<&Dependency> {
+        self.dependencies
+            .binary_search_by(|d| d.name.as_str().cmp(name))
+            .ok()
+            .map(|i| &self.dependencies[i])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    // 文字列中の最初の "1.2.3" 形式 (minor / patch は省略可) を読む
+    pub fn parse(text: &str) -> Option<Self> {
+        let start = text.find(|c: char| c.is_ascii_digit())?;
+        let mut parts = text[start..]
+            .split(|c: char| !c.is_ascii_digit() && c != '.')
+            .next()?
+            .split('.')
+            .map(|p| p.parse::<u64>().ok());
+        Some(Self {
+            major: parts.next().flatten()?,
+            minor: parts.next().flatten().unwrap_or(0),
+            patch: parts.next().flatten().unwrap_or(0),
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+// ルート (無ければその親ディレクトリ) の package.json と、同じディレクトリのロックファイルを読む
+pub fn load(index: &ProjectIndex) -> Option<Packages> {
+    let root = index.root.trim_end_matches('/');
+    let mut dir = root;
+    let manifest = loop {
+        let path = format!("{}/package.json", dir);
+        if index.get(&path).is_some() || Path::new(&path).is_file() {
+            break path;
+        }
+        match dir.rsplit_once('/') {
+            Some((parent, _)) if !parent.is_empty() => dir = parent,
+            _ => return None,
+        }
+    };
+    let value = jsonc::parse(&read(index, &manifest)?)?;
+
+    let lock = LockKind::ALL.iter().find_map(|&(kind, name)| {
+        let path = format!("{}/{}", dir, name);
+        read(index, &path).map(|source| (kind, path, source))
+    });
+    let installed = match &lock {
+        Some((LockKind::Npm, _, source)) => npm_versions(source),
+        Some((LockKind::Yarn, _, source)) => yarn_versions(source),
+        Some((LockKind::Pnpm, _, source)) => pnpm_versions(source),
+        None => HashMap::new(),
+    };
+
+    let mut dependencies = Vec::new();
+    for &(section, dev) in SECTIONS {
+        let Some(entries) = value.get(section).and_then(Value::as_object) else {
+            continue;
+        };
+        for (name, range) in entries {
+            let range = range.as_str().unwrap_or_default().to_string();
+            // yarn.lock は "名前@範囲" ごとにバージョンを持つ
+            let installed = installed
+                .get(name)
+                .or_else(|| installed.get(&format!("{}@{}", name, range)))
+                .or_else(|| installed.get(&format!("{}@npm:{}", name, range)))
+                .cloned();
+            dependencies.push(Dependency {
+                name: name.clone(),
+                range,
+                installed,
+                dev,
+            });
+        }
+    }
+    dependencies.sort_by(|a, b| a.name.cmp(&b.name));
+    dependencies.dedup_by(|a, b| a.name == b.name);
+
+    Some(Packages {
+        manifest,
+        lock: lock.map(|(kind, path, _)| (kind, path)),
+        dependencies,
+    })
+}
+
+fn read(index: &ProjectIndex, path: &str) -> Option<String> {
+    match index.get(path) {
+        Some(file) => file.content().map(String::from),
+        None => fs::read_to_string(path).ok(),
+    }
+}
+
+// package-lock.json (v2 以降は packages、v1 は dependencies)
+fn npm_versions(source: &str) -> HashMap<String, String> {
+    let mut versions = HashMap::new();
+    let Some(value) = jsonc::parse(source) else {
+        return versions;
+    };
+    if let Some(packages) = value.get("packages").and_then(Value::as_object) {
+        for (path, entry) in packages {
+            // 入れ子の node_modules は直接の依存ではない
+            if let Some(name) = path.strip_prefix("node_modules/")
+                && !name.contains("/node_modules/")
+                && let Some(version) = entry.get("version").and_then(Value::as_str)
+            {
+                versions.insert(name.to_string(), version.to_string());
+            }
+        }
+    }
+    if let Some(dependencies) = value.get("dependencies").and_then(Value::as_object) {
+        for (name, entry) in dependencies {
+            if let Some(version) = entry.get("version").and_then(Value::as_str) {
+                versions
+                    .entry(name.clone())
+                    .or_insert_with(|| version.to_string());
+            }
+        }
+    }
+    versions
+}
+
+// yarn.lock (v1 と Berry)。"名前@範囲" をキーにする
+fn yarn_versions(source: &str) -> HashMap<String, String> {
+    let mut versions = HashMap::new();
+    let mut specifiers: Vec<String> = Vec::new();
+    for line in source.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(' ') {
+            // "a@^1.0.0", a@^1.1.0:  または  "a@npm:^1.0.0, a@npm:^1.1.0":
+            specifiers = line
+                .trim_end_matches(':')
+                .split(", ")
+                .map(|s| s.trim().trim_matches('"').to_string())
+                // Berry の先頭にあるロックファイル自体の情報
+                .filter(|s| s != "__metadata")
+                .collect();
+        } else if let Some(rest) = line.trim().strip_prefix("version")
+            && (rest.starts_with(' ') || rest.starts_with(':'))
+        {
+            let version = rest.trim_start_matches(':').trim().trim_matches('"');
+            for specifier in specifiers.drain(..) {
+                versions.insert(specifier, version.to_string());
+            }
+        }
+    }
+    versions
+}
+
+// pnpm-lock.yaml。ワークスペース (v6 の複数プロジェクトと v9) は importers["."] を、
+// 単一プロジェクトの v5 / v6 はトップレベルの dependencies などを読む
+// (v5 は "名前: バージョン"、v6 は "名前:" の下に specifier と version)
+fn pnpm_versions(source: &str) -> HashMap<String, String> {
+    let mut versions = HashMap::new();
+    let is_section = |key: &str| SECTIONS.iter().any(|(section, _)| *section == key);
+    // (インデント, キー) の入れ子
+    let mut stack: Vec<(usize, String)> = Vec::new();
+    for line in source.lines() {
+        let text = line.trim();
+        if text.is_empty() || text.starts_with('#') || text.starts_with("- ") {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        let Some((key, value)) = yaml_entry(text) else {
+            continue;
+        };
+        while stack.last().is_some_and(|(i, _)| *i >= indent) {
+            stack.pop();
+        }
+        if value.is_empty() {
+            stack.push((indent, key));
+            continue;
+        }
+
+        let path: Vec<&str> = stack.iter().map(|(_, k)| k.as_str()).collect();
+        let name = match path.as_slice() {
+            ["importers", ".", section, name] if key == "version" && is_section(section) => *name,
+            [section, name] if key == "version" && is_section(section) => *name,
+            [section] if is_section(section) => key.as_str(),
+            _ => continue,
+        };
+        // "17.3.1(rxjs@7.8.1)" / "17.3.1_rxjs@7.8.1" のような peer 依存の注記を除く
+        let version = value.split(['(', '_']).next().unwrap_or(&value);
+        versions.insert(name.to_string(), version.to_string());
+    }
+    versions
+}
+
+// "key: value" / "'@scope/name':" を (キー, 値) に分ける
+fn yaml_entry(text: &str) -> Option<(String, String)> {
+    let (key, rest) = match text.chars().next()? {
+        quote @ ('\'' | '"') => {
+            let end = text[1..].find(quote)? + 1;
+            (&text[1..end], text[end + 1..].strip_prefix(':')?)
+        }
+        _ => {
+            let (key, rest) = text.split_once(':')?;
+            (key, rest)
+        }
+    };
+    let value = rest.trim().trim_matches(|c| c == '\'' || c == '"');
+    Some((key.to_string(), value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    fn version(text: &str) -> Option<String> {
+        Version::parse(text).map(|v| v.to_string())
+    }
+
+    #[test]
+    fn parse_version() {
+        assert_eq!(version("^17.3.0").as_deref(), Some("17.3.0"));
+        assert_eq!(version("~18.1").as_deref(), Some("18.1.0"));
+        assert_eq!(version(">=19 <20").as_deref(), Some("19.0.0"));
+        assert_eq!(version("17.3.1-next.0").as_deref(), Some("17.3.1"));
+        assert_eq!(version("latest"), None);
+        assert!(Version::parse("17.10.0") > Version::parse("17.9.9"));
+    }
+
+    #[test]
+    fn npm_lock_v1_and_v2() {
+        let v2 = r#"{
+            "lockfileVersion": 3,
+            "packages": {
+                "": { "name": "app" },
+                "node_modules/@angular/core": { "version": "17.3.1" },
+                "node_modules/a/node_modules/rxjs": { "version": "6.0.0" },
+                "node_modules/rxjs": { "version": "7.8.1" }
+            }
+        }"#;
+        let versions = npm_versions(v2);
+        assert_eq!(versions["@angular/core"], "17.3.1");
+        assert_eq!(versions["rxjs"], "7.8.1");
+        assert_eq!(versions.len(), 2);
+
+        let v1 =
+            r#"{ "lockfileVersion": 1, "dependencies": { "zone.js": { "version": "0.14.4" } } }"#;
+        assert_eq!(npm_versions(v1)["zone.js"], "0.14.4");
+        assert!(npm_versions("not json").is_empty());
+    }
+
+    #[test]
+    fn yarn_lock_classic_and_berry() {
+        let classic = "# yarn lockfile v1\n\n\
+            \"@angular/core@^17.3.0\", \"@angular/core@~17.3.1\":\n  \
+              version \"17.3.2\"\n  \
+              resolved \"https://registry/x\"\n\n\
+            rxjs@^7.8.0:\n  \
+              version \"7.8.1\"\n";
+        let versions = yarn_versions(classic);
+        assert_eq!(versions["@angular/core@^17.3.0"], "17.3.2");
+        assert_eq!(versions["@angular/core@~17.3.1"], "17.3.2");
+        assert_eq!(versions["rxjs@^7.8.0"], "7.8.1");
+
+        let berry = "__metadata:\n  version: 8\n\n\
+            \"@angular/core@npm:^18.0.0\":\n  \
+              version: 18.0.5\n  \
+              resolution: \"@angular/core@npm:18.0.5\"\n";
+        let versions = yarn_versions(berry);
+        assert_eq!(versions["@angular/core@npm:^18.0.0"], "18.0.5");
+        assert!(!versions.contains_key("__metadata"));
+    }
+
+    #[test]
+    fn pnpm_lock_v5_v6_and_v9() {
+        let v5 = "lockfileVersion: 5.4\n\
+            specifiers:\n  \
+              '@angular/core': ^16.2.0\n\
+            dependencies:\n  \
+              '@angular/core': 16.2.12_rxjs@7.8.1\n  \
+              rxjs: 7.8.1\n\
+            devDependencies:\n  \
+              typescript: 5.1.6\n\
+            packages:\n  \
+              /rxjs/7.8.1:\n    \
+                dev: false\n";
+        let versions = pnpm_versions(v5);
+        assert_eq!(versions["@angular/core"], "16.2.12");
+        assert_eq!(versions["rxjs"], "7.8.1");
+        assert_eq!(versions["typescript"], "5.1.6");
+        assert_eq!(versions.len(), 3);
+
+        let v6 = "lockfileVersion: '6.0'\n\
+            settings:\n  \
+              autoInstallPeers: true\n\
+            dependencies:\n  \
+              '@angular/core':\n    \
+                specifier: ^17.0.0\n    \
+                version: 17.0.8(rxjs@7.8.1)(zone.js@0.14.2)\n  \
+              rxjs:\n    \
+                specifier: ~7.8.0\n    \
+                version: 7.8.1\n\
+            devDependencies:\n  \
+              typescript:\n    \
+                specifier: ~5.2.2\n    \
+                version: 5.2.2\n\
+            packages:\n  \
+              /rxjs@7.8.1:\n    \
+                dependencies:\n      \
+                  tslib: 2.6.2\n";
+        let versions = pnpm_versions(v6);
+        assert_eq!(versions["@angular/core"], "17.0.8");
+        assert_eq!(versions["rxjs"], "7.8.1");
+        assert_eq!(versions["typescript"], "5.2.2");
+        assert_eq!(versions.len(), 3);
+
+        let v9 = "lockfileVersion: '9.0'\n\
+            importers:\n  \
+              .:\n    \
+                dependencies:\n      \
+                  '@angular/core':\n        \
+                    specifier: ^19.0.0\n        \
+                    version: 19.0.1(rxjs@7.8.1)(zone.js@0.15.0)\n    \
+                devDependencies:\n      \
+                  typescript:\n        \
+                    specifier: ~5.6.0\n        \
+                    version: 5.6.3\n  \
+              libs/ui:\n    \
+                dependencies:\n      \
+                  lodash:\n        \
+                    specifier: ^4.0.0\n        \
+                    version: 4.17.21\n";
+        let versions = pnpm_versions(v9);
+        assert_eq!(versions["@angular/core"], "19.0.1");
+        assert_eq!(versions["typescript"], "5.6.3");
+        assert!(!versions.contains_key("lodash"));
+    }
+
+    #[test]
+    fn load_from_ancestor_with_lock() {
+        let project = TempProject::new(&[
+            (
+                "package.json",
+                r#"{
+                    "dependencies": { "@angular/core": "^17.3.0", "rxjs": "~7.8.0" },
+                    "devDependencies": { "typescript": "~5.4.2" }
+                }"#,
+            ),
+            (
+                "yarn.lock",
+                "\"@angular/core@^17.3.0\":\n  version \"17.3.5\"\n",
+            ),
+            ("apps/a/src/main.ts", ""),
+        ]);
+        let index = ProjectIndex::build(&project.path("apps/a")).unwrap();
+        let packages = load(&index).unwrap();
+        assert_eq!(packages.manifest, project.path("package.json"));
+        assert_eq!(
+            packages.lock.as_ref().map(|(kind, _)| *kind),
+            Some(LockKind::Yarn)
+        );
+
+        let names: Vec<_> = packages
+            .dependencies
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(names, ["@angular/core", "rxjs", "typescript"]);
+        let core = packages.get("@angular/core").unwrap();
+        assert_eq!(core.installed.as_deref(), Some("17.3.5"));
+        // ロックファイルに無ければインストールされたバージョンは不明で、version は範囲の最小バージョン
+        let rxjs = packages.get("rxjs").unwrap();
+        assert_eq!(rxjs.installed, None);
+        assert_eq!(rxjs.installed_version(), None);
+        assert_eq!(
+            rxjs.version().map(|v| v.to_string()).as_deref(),
+            Some("7.8.0")
+        );
+        assert!(packages.get("typescript").unwrap().dev);
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt::Write;
+
+use super::Report;
+use crate::analyzer::AnalysisResult;
+use crate::finding::{Finding, Severity};
+use crate::table::Table;
+
+pub fn render_console(report: &Report) -> String {
+    let mut out = String::new();
+    write_categories(&mut out, &report.categories);
+
+    // --projects 指定時はプロジェクトごとの結果を続け、最後に判定の表を出す
+    for project in &report.projects {
+        writeln!(
+            out,
+            "##### プロジェクト: {} ({}) #####\n",
+            project.name,
+            report.relative(&project.root)
+        )
+        .unwrap();
+        write_categories(&mut out, &project.categories);
+        writeln!(out, "{} の判定: {}\n", project.name, project.status).unwrap();
+    }
+    if let Some(table) = report.project_table() {
+        write_table(&mut out, &table);
+        writeln!(out).unwrap();
+    }
+
+    writeln!(out, "総合判定: {}", report.status).unwrap();
+    out
+}
+
+fn write_categories(out: &mut String, categories: &[AnalysisResult]) {
+    for result in categories {
+        writeln!(out, "=== {} ===", result.category).unwrap();
+        writeln!(out, "ステータス: {}", result.status).unwrap();
+
+        if !result.details.is_empty() {
+            writeln!(out, "詳細:").unwrap();
+            for detail in &result.details {
+                writeln!(out, "  ✓ {}", detail).unwrap();
+            }
+        }
+
+        for table in &result.tables {
+            write_table(out, table);
+        }
+
+        for graph in &result.graphs {
+            writeln!(
+                out,
+                "グラフ: {} (ID: {}, ノード {} / 辺 {}、--format dot|mermaid で出力)",
+                graph.title,
+                graph.id,
+                graph.nodes().len(),
+                graph.edges().len()
+            )
+            .unwrap();
+        }
+
+        let infos: Vec<_> = result.findings_with(Severity::Info).collect();
+        if !infos.is_empty() {
+            writeln!(out, "情報:").unwrap();
+            for info in infos {
+                writeln!(out, "  ℹ {}", format_finding(info)).unwrap();
+            }
+        }
+
+        let warnings: Vec<_> = result.findings_with(Severity::Warning).collect();
+        if !warnings.is_empty() {
+            writeln!(out, "警告:").unwrap();
+            for warning in warnings {
+                writeln!(out, "  ⚠ {}", format_finding(warning)).unwrap();
+            }
+        }
+
+        let errors: Vec<_> = result.findings_with(Severity::Error).collect();
+        if !errors.is_empty() {
+            writeln!(out, "エラー:").unwrap();
+            for error in errors {
+                writeln!(out, "  ✗ {}", format_finding(error)).unwrap();
+            }
+        }
+
+        writeln!(out).unwrap();
+    }
+}
+
+fn format_finding(finding: &Finding) -> String {
+    let mut line = format!("{} [{}]", finding.message, finding.rule_id);
+    if let Some(location) = &finding.location {
+        line.push_str(&format!("\n      {}", location));
+    }
+    if let Some(snippet) = &finding.snippet {
+        line.push_str(&format!("\n      | {}", snippet));
+    }
+    line
+}
+
+// 列幅をそろえた表 (全角文字は2桁として数える)
+fn write_table(out: &mut String, table: &Table) {
+    let cell = |value: &str| {
+        if value.is_empty() {
+            "-".to_string()
+        } else {
+            value.to_string()
+        }
+    };
+    let mut widths: Vec<usize> = table.columns.iter().map(|c| display_width(c)).collect();
+    for row in &table.rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(display_width(&cell(value)));
+        }
+    }
+
+    let line = |values: Vec<String>| {
+        let cells: Vec<String> = values
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| {
+                let padding = width.saturating_sub(display_width(value));
+                format!("{}{}", value, " ".repeat(padding))
+            })
+            .collect();
+        format!("  | {} |", cells.join(" | "))
+    };
+
+    writeln!(out, "{}:", table.title).unwrap();
+    writeln!(out, "{}", line(table.columns.clone())).unwrap();
+    writeln!(
+        out,
+        "{}",
+        line(widths.iter().map(|w| "-".repeat(*w)).collect())
+    )
+    .unwrap();
+    for row in &table.rows {
+        writeln!(out, "{}", line(row.iter().map(|v| cell(v)).collect())).unwrap();
+    }
+}
+
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6 => 2,
+            _ => 1,
+        })
+        .sum()
+}
@@ -0,0 +1,24 @@
+use std::fmt::Write;
+
+use super::Report;
+
+// 全グラフを DOT 形式で続けて出力する (Graphviz は複数の digraph を順に描画する)
+pub fn render_dot(report: &Report) -> String {
+    report
+        .categories
+        .iter()
+        .flat_map(|c| &c.graphs)
+        .map(|graph| graph.to_dot())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// 各グラフを見出し付きの Mermaid コードブロックとして Markdown で出力する
+pub fn render_mermaid(report: &Report) -> String {
+    let mut out = String::new();
+    for graph in report.categories.iter().flat_map(|c| &c.graphs) {
+        writeln!(out, "## {}\n", graph.title).unwrap();
+        writeln!(out, "```mermaid\n{}```\n", graph.to_mermaid()).unwrap();
+    }
+    out
+}
@@ -0,0 +1,318 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::Report;
+use crate::analyzer::{AnalysisResult, Status};
+use crate::finding::{Finding, Severity};
+use crate::table::Table;
+
+const STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #222; }
+h1 { margin-bottom: 0.2rem; }
+.meta { color: #666; margin-bottom: 1.5rem; }
+.scorecard { display: grid; grid-template-columns: repeat(auto-fill, minmax(14rem, 1fr)); gap: 0.8rem; }
+.card { border: 1px solid #ddd; border-left-width: 6px; border-radius: 4px; padding: 0.6rem 0.8rem; text-decoration: none; color: inherit; }
+.card .name { font-weight: bold; }
+.card .counts { font-size: 0.85rem; color: #555; }
+.OK { border-left-color: #2e7d32; }
+.WARNING { border-left-color: #f9a825; }
+.ERROR { border-left-color: #c62828; }
+.SKIPPED { border-left-color: #9e9e9e; }
+.badge { display: inline-block; padding: 0 0.4rem; border-radius: 3px; font-size: 0.8rem; color: #fff; }
+.badge.OK { background: #2e7d32; }
+.badge.WARNING, .badge.warning { background: #f9a825; }
+.badge.ERROR, .badge.error { background: #c62828; }
+.badge.SKIPPED, .badge.info { background: #757575; }
+section { margin-top: 2rem; }
+details { border: 1px solid #eee; border-radius: 4px; margin: 0.4rem 0; padding: 0.4rem 0.6rem; }
+summary { cursor: pointer; }
+pre { background: #f6f8fa; padding: 0.5rem; overflow-x: auto; }
+table { border-collapse: collapse; margin-top: 0.5rem; }
+th, td { border: 1px solid #ddd; padding: 0.3rem 0.6rem; text-align: left; }
+th { background: #f5f5f5; }
+.location { font-family: monospace; color: #555; }
+"#;
+
+// 外部リソースに依存しない単一の HTML ファイル
+pub fn render_html(report: &Report) -> String {
+    let mut out = String::new();
+    writeln!(out, "<!DOCTYPE html>").unwrap();
+    writeln!(out, "<html lang=\"ja\">\n<head>\n<meta charset=\"utf-8\">").unwrap();
+    writeln!(
+        out,
+        "<title>解析レポート - {}</title>",
+        escape(&report.project_path)
+    )
+    .unwrap();
+    writeln!(out, "<style>{}</style>\n</head>\n<body>", STYLE).unwrap();
+
+    writeln!(out, "<h1>フロントエンド解析レポート</h1>").unwrap();
+    writeln!(
+        out,
+        "<div class=\"meta\">{} &middot; {} {} &middot; {} &middot; 総合判定 <span class=\"badge {}\">{}</span></div>",
+        escape(&report.project_path),
+        escape(&report.tool.name),
+        escape(&report.tool.version),
+        escape(&report.generated_at),
+        report.status,
+        report.status
+    )
+    .unwrap();
+
+    render_scorecard(&mut out, &report.categories, "category");
+    render_file_table(&mut out, report);
+    if let Some(table) = report.project_table() {
+        writeln!(out, "<section>").unwrap();
+        render_table(&mut out, &table, 3);
+        writeln!(out, "</section>").unwrap();
+    }
+    render_categories(&mut out, report, &report.categories, "category", 2);
+
+    // --projects 指定時はプロジェクトごとにカテゴリと指摘を出す
+    for (i, project) in report.projects.iter().enumerate() {
+        let id = format!("project-{}", i);
+        writeln!(
+            out,
+            "<section id=\"{}\">\n<h2>プロジェクト: {} <span class=\"badge {}\">{}</span></h2>",
+            id,
+            escape(&project.name),
+            project.status,
+            project.status
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "<div class=\"meta\">{}</div>",
+            escape(report.relative(&project.root))
+        )
+        .unwrap();
+        let prefix = format!("{}-category", id);
+        render_scorecard(&mut out, &project.categories, &prefix);
+        render_categories(&mut out, report, &project.categories, &prefix, 3);
+        writeln!(out, "</section>").unwrap();
+    }
+
+    writeln!(out, "</body>\n</html>").unwrap();
+    out
+}
+
+// カテゴリごとの詳細・表・グラフ・指摘 (見出しは level、表の見出しはその 1 つ下)
+fn render_categories(
+    out: &mut String,
+    report: &Report,
+    categories: &[AnalysisResult],
+    prefix: &str,
+    level: usize,
+) {
+    for (i, result) in categories.iter().enumerate() {
+        writeln!(
+            out,
+            "<section id=\"{}-{}\">\n<h{}>{} <span class=\"badge {}\">{}</span></h{}>",
+            prefix,
+            i,
+            level,
+            escape(&result.category),
+            result.status,
+            result.status,
+            level
+        )
+        .unwrap();
+
+        if !result.details.is_empty() {
+            writeln!(out, "<ul>").unwrap();
+            for detail in &result.details {
+                writeln!(out, "<li>{}</li>", escape(detail)).unwrap();
+            }
+            writeln!(out, "</ul>").unwrap();
+        }
+
+        for table in &result.tables {
+            render_table(out, table, level + 1);
+        }
+
+        // グラフは外部スクリプト無しでは描画できないため Mermaid のソースを添える
+        for graph in &result.graphs {
+            writeln!(
+                out,
+                "<details>\n<summary>{} (Mermaid)</summary>\n<pre>{}</pre>\n</details>",
+                escape(&graph.title),
+                escape(&graph.to_mermaid())
+            )
+            .unwrap();
+        }
+
+        for finding in &result.findings {
+            render_finding(out, report, finding);
+        }
+        writeln!(out, "</section>").unwrap();
+    }
+}
+
+fn render_scorecard(out: &mut String, categories: &[AnalysisResult], prefix: &str) {
+    writeln!(out, "<div class=\"scorecard\">").unwrap();
+    for (i, result) in categories.iter().enumerate() {
+        let warnings = result.findings_with(Severity::Warning).count();
+        let errors = result.findings_with(Severity::Error).count();
+        writeln!(
+            out,
+            "<a class=\"card {}\" href=\"#{}-{}\"><div class=\"name\">{}</div><div>{}</div><div class=\"counts\">警告 {} / エラー {}</div></a>",
+            result.status,
+            prefix,
+            i,
+            escape(&result.category),
+            status_label(result.status),
+            warnings,
+            errors
+        )
+        .unwrap();
+    }
+    writeln!(out, "</div>").unwrap();
+}
+
+// ファイルごとの指摘数
+fn render_file_table(out: &mut String, report: &Report) {
+    let mut files: BTreeMap<&str, (usize, usize, usize)> = BTreeMap::new();
+    for finding in report.categories.iter().flat_map(|c| &c.findings) {
+        if let Some(location) = &finding.location {
+            let counts = files.entry(report.relative(&location.file)).or_default();
+            match finding.severity {
+                Severity::Info => counts.0 += 1,
+                Severity::Warning => counts.1 += 1,
+                Severity::Error => counts.2 += 1,
+            }
+        }
+    }
+    if files.is_empty() {
+        return;
+    }
+
+    writeln!(out, "<section>\n<h2>ファイル別の指摘</h2>").unwrap();
+    writeln!(
+        out,
+        "<table>\n<tr><th>ファイル</th><th>情報</th><th>警告</th><th>エラー</th></tr>"
+    )
+    .unwrap();
+    for (file, (info, warning, error)) in files {
+        writeln!(
+            out,
+            "<tr><td class=\"location\">{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            escape(file),
+            info,
+            warning,
+            error
+        )
+        .unwrap();
+    }
+    writeln!(out, "</table>\n</section>").unwrap();
+}
+
+fn render_table(out: &mut String, table: &Table, level: usize) {
+    writeln!(
+        out,
+        "<h{}>{}</h{}>\n<table>\n<tr>",
+        level,
+        escape(&table.title),
+        level
+    )
+    .unwrap();
+    for column in &table.columns {
+        write!(out, "<th>{}</th>", escape(column)).unwrap();
+    }
+    writeln!(out, "</tr>").unwrap();
+    for row in &table.rows {
+        write!(out, "<tr>").unwrap();
+        for value in row {
+            write!(out, "<td>{}</td>", escape(value)).unwrap();
+        }
+        writeln!(out, "</tr>").unwrap();
+    }
+    writeln!(out, "</table>").unwrap();
+}
+
+fn render_finding(out: &mut String, report: &Report, finding: &Finding) {
+    writeln!(
+        out,
+        "<details>\n<summary><span class=\"badge {}\">{}</span> {} <code>{}</code></summary>",
+        finding.severity,
+        finding.severity,
+        escape(&finding.message),
+        escape(&finding.rule_id)
+    )
+    .unwrap();
+    match &finding.location {
+        Some(location) => writeln!(
+            out,
+            "<div class=\"location\">{}:{}:{}</div>",
+            escape(report.relative(&location.file)),
+            location.span.start_line,
+            location.span.start_column
+        )
+        .unwrap(),
+        None => writeln!(out, "<div class=\"location\">プロジェクト全体</div>").unwrap(),
+    }
+    if let Some(snippet) = &finding.snippet {
+        writeln!(out, "<pre>{}</pre>", escape(snippet)).unwrap();
+    }
+    writeln!(out, "</details>").unwrap();
+}
+
+fn status_label(status: Status) -> &'static str {
+    match status {
+        Status::Ok => "問題なし",
+        Status::Warning => "警告あり",
+        Status::Error => "エラーあり",
+        Status::Skipped => "スキップ",
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{ProjectReport, Summary};
+
+    #[test]
+    fn project_sections_with_relative_paths() {
+        let category = || {
+            let mut result = AnalysisResult::new("テスト");
+            result
+                .findings
+                .push(Finding::new("test/rule", Severity::Warning, "指摘").at(
+                    "/work/apps/shop/src/main.ts",
+                    "x",
+                    0,
+                    1,
+                ));
+            result.status = Status::from_findings(&result.findings);
+            result
+        };
+        let project = ProjectReport {
+            name: "shop".to_string(),
+            root: "/work/apps/shop".to_string(),
+            project_type: "application".to_string(),
+            tags: Vec::new(),
+            status: Status::Warning,
+            summary: Summary::new(&[category()]),
+            categories: vec![category()],
+        };
+        let report = Report::new("/work", vec![category()]).with_projects(vec![project]);
+        let html = render_html(&report);
+
+        assert!(html.contains(
+            "<tr><td class=\"location\">apps/shop/src/main.ts</td><td>0</td><td>1</td><td>0</td></tr>"
+        ));
+        assert!(html.contains("<div class=\"location\">apps/shop/src/main.ts:1:1</div>"));
+        assert!(!html.contains("/work/apps/shop/src/main.ts"));
+        assert!(html.contains(
+            "<section id=\"project-0\">\n<h2>プロジェクト: shop <span class=\"badge WARNING\">WARNING</span></h2>"
+        ));
+        assert!(html.contains("href=\"#project-0-category-0\""));
+        assert!(html.contains("<section id=\"project-0-category-0\">\n<h3>テスト"));
+    }
+}
@@ -0,0 +1,70 @@
+use super::Report;
+
+pub fn render_json(report: &Report) -> String {
+    serde_json::to_string_pretty(report).expect("レポートは常にJSONへ変換できる")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::{AnalysisResult, Status};
+    use crate::finding::{Finding, Severity};
+    use crate::report::SCHEMA_VERSION;
+    use serde_json::Value;
+
+    #[test]
+    fn document_schema() {
+        let mut result = AnalysisResult::new("セキュリティ");
+        result.id = "security".to_string();
+        result.push(Finding::new("security/eval", Severity::Error, "eval").at(
+            "/work/src/a.ts",
+            "eval(x);\n",
+            0,
+            4,
+        ));
+        result.push(Finding::new("security/csp", Severity::Info, "CSP"));
+        result.update_status();
+        let report = Report::new("/work", vec![result, AnalysisResult::skipped("Nx", "なし")]);
+
+        let json: Value = serde_json::from_str(&render_json(&report)).unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(json["tool"]["name"], env!("CARGO_PKG_NAME"));
+        assert_eq!(json["tool"]["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(json["project_path"], "/work");
+        assert!(json["generated_at"].is_string());
+        assert_eq!(json["status"], "ERROR");
+        assert_eq!(
+            json["summary"],
+            serde_json::json!({
+                "categories": 2, "ok": 0, "warning": 0, "error": 1, "skipped": 1,
+                "findings": { "total": 2, "info": 1, "warning": 0, "error": 1 }
+            })
+        );
+        // projects は --projects 指定時だけ、tables / graphs は空なら出力しない
+        assert!(json.get("projects").is_none());
+
+        let category = &json["categories"][0];
+        assert_eq!(category["id"], "security");
+        assert_eq!(category["category"], "セキュリティ");
+        assert_eq!(category["status"], "ERROR");
+        assert!(category.get("tables").is_none());
+        let finding = &category["findings"][0];
+        assert_eq!(finding["rule_id"], "security/eval");
+        assert_eq!(finding["severity"], "error");
+        assert_eq!(
+            finding["location"],
+            serde_json::json!({
+                "file": "/work/src/a.ts",
+                "start_line": 1, "start_column": 1, "end_line": 1, "end_column": 5
+            })
+        );
+        assert_eq!(finding["snippet"], "eval(x);");
+        assert!(category["findings"][1].get("location").is_none());
+        assert_eq!(json["categories"][1]["status"], "SKIPPED");
+
+        // 出力した JSON はそのまま読み戻せる
+        let parsed: Report = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.status, Status::Error);
+        assert_eq!(parsed.categories[0].findings.len(), 2);
+    }
+}
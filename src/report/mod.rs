@@ -0,0 +1,247 @@
+mod console;
+mod graph;
+mod html;
+mod json;
+mod sarif;
+
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{AnalysisResult, Status};
+use crate::finding::{Rule, Severity};
+use crate::table::Table;
+use crate::time::rfc3339_now;
+use crate::workspace::Project;
+
+pub use console::render_console;
+pub use graph::{render_dot, render_mermaid};
+pub use html::render_html;
+pub use json::render_json;
+pub use sarif::render_sarif;
+
+// JSON 出力の形式を変更した場合はこの値を上げる
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Console,
+    Json,
+    Sarif,
+    Html,
+    Dot,
+    Mermaid,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "console" | "text" => Some(Format::Console),
+            "json" => Some(Format::Json),
+            "sarif" => Some(Format::Sarif),
+            "html" => Some(Format::Html),
+            "dot" => Some(Format::Dot),
+            "mermaid" => Some(Format::Mermaid),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ToolInfo {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Summary {
+    pub categories: usize,
+    pub ok: usize,
+    pub warning: usize,
+    pub error: usize,
+    pub skipped: usize,
+    pub findings: FindingCounts,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FindingCounts {
+    pub total: usize,
+    pub info: usize,
+    pub warning: usize,
+    pub error: usize,
+}
+
+impl Summary {
+    pub fn new(categories: &[AnalysisResult]) -> Self {
+        let mut summary = Summary {
+            categories: categories.len(),
+            ..Summary::default()
+        };
+        for result in categories {
+            match result.status {
+                Status::Ok => summary.ok += 1,
+                Status::Warning => summary.warning += 1,
+                Status::Error => summary.error += 1,
+                Status::Skipped => summary.skipped += 1,
+            }
+            for finding in &result.findings {
+                summary.findings.total += 1;
+                match finding.severity {
+                    Severity::Info => summary.findings.info += 1,
+                    Severity::Warning => summary.findings.warning += 1,
+                    Severity::Error => summary.findings.error += 1,
+                }
+            }
+        }
+        summary
+    }
+}
+
+// ワークスペース内の 1 プロジェクトを個別に解析した結果
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectReport {
+    pub name: String,
+    // 解析したディレクトリ (sourceRoot があればそちら)
+    pub root: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub project_type: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub status: Status,
+    pub summary: Summary,
+    pub categories: Vec<AnalysisResult>,
+}
+
+impl ProjectReport {
+    pub fn new(project: Project, categories: Vec<AnalysisResult>) -> Self {
+        Self {
+            root: project.analysis_root().to_string(),
+            name: project.name,
+            project_type: project.project_type,
+            tags: project.tags,
+            status: Status::overall(&categories),
+            summary: Summary::new(&categories),
+            categories,
+        }
+    }
+}
+
+// 各出力形式が共通で使う解析結果一式
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub schema_version: u32,
+    pub tool: ToolInfo,
+    pub project_path: String,
+    pub generated_at: String,
+    pub status: Status,
+    pub summary: Summary,
+    pub categories: Vec<AnalysisResult>,
+    // --projects 指定時のプロジェクトごとの結果 (総合判定には含めない)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ProjectReport>,
+    // 実行した解析器のルール定義 (SARIF の reportingDescriptor に使う)
+    #[serde(skip)]
+    pub rules: Vec<Rule>,
+}
+
+impl Report {
+    pub fn new(project_path: &str, categories: Vec<AnalysisResult>) -> Self {
+        let summary = Summary::new(&categories);
+
+        Self {
+            schema_version: SCHEMA_VERSION,
+            tool: ToolInfo {
+                name: env!("CARGO_PKG_NAME").to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+            project_path: project_path.to_string(),
+            generated_at: rfc3339_now(),
+            status: Status::overall(&categories),
+            summary,
+            categories,
+            projects: Vec::new(),
+            rules: Vec::new(),
+        }
+    }
+
+    pub fn with_projects(mut self, projects: Vec<ProjectReport>) -> Self {
+        self.projects = projects;
+        self
+    }
+
+    // 解析したパスからの相対パス (表示用)
+    pub fn relative<'a>(&self, path: &'a str) -> &'a str {
+        match path.strip_prefix(self.project_path.trim_end_matches('/')) {
+            Some(rest) if rest.is_empty() || rest.starts_with('/') => rest.trim_start_matches('/'),
+            _ => path,
+        }
+    }
+
+    // プロジェクトごとの判定をまとめた表 (プロジェクト別の結果が無ければ None)
+    pub fn project_table(&self) -> Option<Table> {
+        if self.projects.is_empty() {
+            return None;
+        }
+        let mut table = Table::new(
+            "プロジェクト別の判定",
+            &[
+                "プロジェクト",
+                "種別",
+                "ルート",
+                "判定",
+                "警告",
+                "エラー",
+                "要対応のカテゴリ",
+            ],
+        );
+        for project in &self.projects {
+            let attention: Vec<&str> = project
+                .categories
+                .iter()
+                .filter(|c| matches!(c.status, Status::Warning | Status::Error))
+                .map(|c| c.category.as_str())
+                .collect();
+            let root = self.relative(&project.root);
+            table.push_row(vec![
+                project.name.clone(),
+                project.project_type.clone(),
+                root.to_string(),
+                project.status.to_string(),
+                project.summary.findings.warning.to_string(),
+                project.summary.findings.error.to_string(),
+                attention.join(", "),
+            ]);
+        }
+        Some(table)
+    }
+
+    pub fn with_rules(mut self, rules: Vec<Rule>) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    // 指定した ID のグラフだけを残す。該当するグラフが無ければ false
+    pub fn retain_graph(&mut self, id: &str) -> bool {
+        for result in &mut self.categories {
+            result.graphs.retain(|g| g.id == id);
+        }
+        self.categories.iter().any(|c| !c.graphs.is_empty())
+    }
+
+    pub fn graph_ids(&self) -> Vec<&str> {
+        self.categories
+            .iter()
+            .flat_map(|c| &c.graphs)
+            .map(|g| g.id.as_str())
+            .collect()
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Console => render_console(self),
+            Format::Json => render_json(self),
+            Format::Sarif => render_sarif(self),
+            Format::Html => render_html(self),
+            Format::Dot => render_dot(self),
+            Format::Mermaid => render_mermaid(self),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use serde_json::{Value, json};
+use std::path::Path;
+
+use super::Report;
+use crate::finding::{Finding, Severity};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+// SARIF 2.1.0 形式。ファイル位置を持つ指摘のみ result として出力する
+pub fn render_sarif(report: &Report) -> String {
+    let mut rule_ids: Vec<&str> = report.rules.iter().map(|r| r.id).collect();
+    let mut rules: Vec<Value> = report
+        .rules
+        .iter()
+        .map(|rule| {
+            json!({
+                "id": rule.id,
+                "shortDescription": { "text": rule.description },
+                "defaultConfiguration": { "level": level(rule.severity) },
+            })
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for finding in report.categories.iter().flat_map(|c| &c.findings) {
+        let Some(location) = &finding.location else {
+            continue;
+        };
+
+        // 登録されていないルール (独自解析器など) は ID のみで定義する
+        let rule_index = match rule_ids.iter().position(|id| *id == finding.rule_id) {
+            Some(i) => i,
+            None => {
+                rule_ids.push(&finding.rule_id);
+                rules.push(json!({ "id": finding.rule_id }));
+                rule_ids.len() - 1
+            }
+        };
+
+        let mut physical_location = json!({
+            "artifactLocation": {
+                "uri": relative_uri(&report.project_path, finding),
+                "uriBaseId": "%SRCROOT%",
+            },
+            "region": {
+                "startLine": location.span.start_line,
+                "startColumn": location.span.start_column,
+                "endLine": location.span.end_line,
+                "endColumn": location.span.end_column,
+            },
+        });
+        // スニペットは該当行全体なので contextRegion として添える
+        if let Some(snippet) = &finding.snippet {
+            physical_location["contextRegion"] = json!({
+                "startLine": location.span.start_line,
+                "endLine": location.span.start_line,
+                "snippet": { "text": snippet },
+            });
+        }
+
+        results.push(json!({
+            "ruleId": finding.rule_id,
+            "ruleIndex": rule_index,
+            "level": level(finding.severity),
+            "message": { "text": finding.message },
+            "locations": [{ "physicalLocation": physical_location }],
+        }));
+    }
+
+    // 一覧表は SARIF に対応する要素が無いため run のプロパティとして添える
+    let tables: Vec<Value> = report
+        .categories
+        .iter()
+        .flat_map(|c| {
+            c.tables.iter().map(move |table| {
+                json!({
+                    "category": c.id,
+                    "title": table.title,
+                    "columns": table.columns,
+                    "rows": table.rows,
+                })
+            })
+        })
+        .collect();
+
+    let sarif = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            // 列番号は文字数で数えている (finding::Span)
+            "columnKind": "unicodeCodePoints",
+            "tool": {
+                "driver": {
+                    "name": report.tool.name,
+                    "version": report.tool.version,
+                    "rules": rules,
+                }
+            },
+            "originalUriBaseIds": {
+                "%SRCROOT%": { "uri": root_uri(&report.project_path) }
+            },
+            "results": results,
+            "properties": { "tables": tables },
+        }]
+    });
+
+    serde_json::to_string_pretty(&sarif).expect("SARIFは常にJSONへ変換できる")
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "note",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+// プロジェクトルートからの相対パス (区切りは常に '/')
+fn relative_uri(project_path: &str, finding: &Finding) -> String {
+    let file = &finding.location.as_ref().unwrap().file;
+    let relative = Path::new(file)
+        .strip_prefix(project_path)
+        .unwrap_or(Path::new(file));
+    relative
+        .components()
+        .map(|c| encode_uri(&c.as_os_str().to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn root_uri(project_path: &str) -> String {
+    let absolute = Path::new(project_path)
+        .canonicalize()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_else(|_| project_path.replace('\\', "/"));
+    let absolute = encode_uri(absolute.trim_end_matches('/'));
+    if absolute.starts_with('/') {
+        format!("file://{}/", absolute)
+    } else {
+        format!("file:///{}/", absolute)
+    }
+}
+
+// URI のパス部分として使えない文字 (空白や非 ASCII 文字など) を UTF-8 のバイト単位で % エンコードする
+fn encode_uri(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::AnalysisResult;
+    use crate::finding::Rule;
+
+    const RULE: Rule = Rule {
+        id: "test/rule",
+        severity: Severity::Warning,
+        description: "テスト用のルール",
+    };
+
+    #[test]
+    fn located_finding() {
+        let content = "const a = 1;\nconst 名前 = b;\n";
+        let start = content.find('b').unwrap();
+        let mut result = AnalysisResult::new("テスト");
+        result.findings.push(RULE.finding("指摘").at(
+            "/work/my app/src/ページ.ts",
+            content,
+            start,
+            start + 1,
+        ));
+        result.findings.push(RULE.finding("位置の無い指摘"));
+        let report = Report::new("/work/my app", vec![result]).with_rules(vec![RULE]);
+
+        let sarif: Value = serde_json::from_str(&render_sarif(&report)).unwrap();
+        let run = &sarif["runs"][0];
+        assert_eq!(run["columnKind"], "unicodeCodePoints");
+        assert_eq!(
+            run["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+            "file:///work/my%20app/"
+        );
+        assert_eq!(run["results"].as_array().unwrap().len(), 1);
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "test/rule");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"]["uri"],
+            "src/%E3%83%9A%E3%83%BC%E3%82%B8.ts"
+        );
+        assert_eq!(location["region"]["startLine"], 2);
+        // "const 名前 = " の後ろ (バイト数ではなく文字数で数える)
+        assert_eq!(location["region"]["startColumn"], 12);
+        assert_eq!(
+            location["contextRegion"]["snippet"]["text"],
+            "const 名前 = b;"
+        );
+    }
+}
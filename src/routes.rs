@@ -0,0 +1,575 @@
+// Routes 配列から組み立てたルートツリー
+//
+// RouterModule.forRoot / provideRouter に渡された配列を起点に、children と
+// loadChildren の読み込み先 (別ファイルの Routes 変数や forChild) をたどる。
+// 起点が無いプロジェクト (ライブラリ等) では、他から読み込まれない Routes 型の変数を起点にする。
+
+use std::collections::HashSet;
+
+use crate::index::{FileKind, ProjectIndex};
+use crate::ts::{Expr, ExprKind, Module, TextRange};
+
+// ガードやリゾルバーをルート定義の中に関数で書いた場合の名前
+pub const INLINE_FUNCTION: &str = "(インライン関数)";
+
+// loadChildren の入れ子の上限 (循環する読み込みを打ち切る)
+const MAX_DEPTH: usize = 16;
+
+#[derive(Debug, Clone)]
+pub struct Route {
+    // 親からの相対パス ("" や "**" を含む)
+    pub path: String,
+    // 先頭に "/" を付けた完全なパス
+    pub full_path: String,
+    // 名前付きの router-outlet (省略時は None で primary)
+    pub outlet: Option<String>,
+    pub component: Option<String>,
+    // loadComponent / loadChildren の読み込み先 (解決できたファイル、できなければ指定子)
+    pub load_component: Option<String>,
+    pub load_children: Option<String>,
+    pub redirect_to: Option<String>,
+    pub can_activate: Vec<String>,
+    pub can_activate_child: Vec<String>,
+    pub can_match: Vec<String>,
+    pub can_deactivate: Vec<String>,
+    pub resolvers: Vec<String>,
+    // このルートへの遷移で実行されるガード (祖先の canActivate / canActivateChild / canMatch を含む)
+    pub effective_guards: Vec<String>,
+    pub children: Vec<Route>,
+    // ルート定義のオブジェクトの位置
+    pub file: String,
+    pub range: TextRange,
+}
+
+impl Route {
+    pub fn is_lazy(&self) -> bool {
+        self.load_component.is_some() || self.load_children.is_some()
+    }
+
+    pub fn is_redirect(&self) -> bool {
+        self.redirect_to.is_some()
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        self.path == "**"
+    }
+
+    // 表示用の完全なパス (名前付きの outlet は併記する)
+    pub fn path_with_outlet(&self) -> String {
+        match &self.outlet {
+            Some(outlet) => format!("{} (outlet: {})", self.full_path, outlet),
+            None => self.full_path.clone(),
+        }
+    }
+}
+
+// ツリーを深さ優先でたどる (depth はルート直下が 0)
+pub fn walk<'a>(routes: &'a [Route], f: &mut impl FnMut(&'a Route, usize)) {
+    fn visit<'a>(routes: &'a [Route], depth: usize, f: &mut impl FnMut(&'a Route, usize)) {
+        for route in routes {
+            f(route, depth);
+            visit(&route.children, depth + 1, f);
+        }
+    }
+    visit(routes, 0, f);
+}
+
+// プロジェクトのルートツリーを組み立てる
+pub fn collect_routes(index: &ProjectIndex) -> Vec<Route> {
+    let mut builder = Builder {
+        index,
+        loaded: HashSet::new(),
+    };
+
+    let mut roots = Vec::new();
+    for file in index.files(FileKind::Ts) {
+        let Some(module) = file.module() else {
+            continue;
+        };
+        for call in &module.calls {
+            if (call.callee == "RouterModule.forRoot" || call.callee == "provideRouter")
+                && let Some(routes) = call.args.first()
+            {
+                roots.extend(builder.routes(&file.path, module, routes, "/", &[], 0));
+            }
+        }
+    }
+    if !roots.is_empty() {
+        return roots;
+    }
+
+    // 起点が無ければ Routes 型の変数ごとに組み立て、他の変数から読み込まれたものを除く
+    let mut candidates = Vec::new();
+    for file in index.files(FileKind::Ts) {
+        let Some(module) = file.module() else {
+            continue;
+        };
+        for variable in &module.variables {
+            if !is_routes_type(variable.type_annotation.as_deref()) {
+                continue;
+            }
+            if let Some(init) = &variable.init {
+                let routes = builder.routes(&file.path, module, init, "/", &[], 0);
+                candidates.push(((file.path.clone(), variable.name.clone()), routes));
+            }
+        }
+    }
+    candidates
+        .into_iter()
+        .filter(|(key, _)| !builder.loaded.contains(key))
+        .flat_map(|(_, routes)| routes)
+        .collect()
+}
+
+fn is_routes_type(annotation: Option<&str>) -> bool {
+    matches!(annotation.map(str::trim), Some("Routes" | "Route[]"))
+}
+
+struct Builder<'a> {
+    index: &'a ProjectIndex,
+    // 他のルート配列から読み込まれた (ファイル, 変数名)
+    loaded: HashSet<(String, String)>,
+}
+
+impl<'a> Builder<'a> {
+    fn routes(
+        &mut self,
+        path: &str,
+        module: &'a Module,
+        expr: &'a Expr,
+        parent: &str,
+        inherited: &[String],
+        depth: usize,
+    ) -> Vec<Route> {
+        let mut routes = Vec::new();
+        if depth > MAX_DEPTH {
+            return routes;
+        }
+        for item in expr.items() {
+            match &item.kind {
+                ExprKind::Object(_) => {
+                    routes.push(self.route(path, module, item, parent, inherited, depth));
+                }
+                ExprKind::Spread(inner) => {
+                    routes.extend(self.routes(path, module, inner, parent, inherited, depth + 1))
+                }
+                ExprKind::Ident(name) => {
+                    if let Some((path, module, init)) = self.variable(path, module, name) {
+                        self.loaded.insert((path.clone(), name.clone()));
+                        routes.extend(self.routes(
+                            &path,
+                            module,
+                            init,
+                            parent,
+                            inherited,
+                            depth + 1,
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+        routes
+    }
+
+    fn route(
+        &mut self,
+        path: &str,
+        module: &'a Module,
+        expr: &'a Expr,
+        parent: &str,
+        inherited: &[String],
+        depth: usize,
+    ) -> Route {
+        let get = |key: &str| expr.get(key);
+        let segment = get("path").and_then(Expr::as_str).unwrap_or_default();
+        let full_path = match (parent, segment) {
+            (parent, "") => parent.to_string(),
+            ("/", segment) => format!("/{}", segment),
+            (parent, segment) => format!("{}/{}", parent, segment),
+        };
+
+        let mut route = Route {
+            path: segment.to_string(),
+            full_path,
+            outlet: get("outlet")
+                .and_then(Expr::as_str)
+                .filter(|o| *o != "primary")
+                .map(str::to_string),
+            component: get("component").and_then(Expr::path),
+            load_component: get("loadComponent")
+                .and_then(import_target)
+                .map(|(specifier, _)| self.resolve(path, specifier)),
+            load_children: None,
+            redirect_to: get("redirectTo").map(|value| {
+                value
+                    .as_str()
+                    .map_or_else(|| "(関数)".to_string(), str::to_string)
+            }),
+            can_activate: guard_names(get("canActivate")),
+            can_activate_child: guard_names(get("canActivateChild")),
+            can_match: guard_names(get("canMatch"))
+                .into_iter()
+                .chain(guard_names(get("canLoad")))
+                .collect(),
+            can_deactivate: guard_names(get("canDeactivate")),
+            resolvers: get("resolve")
+                .and_then(Expr::as_object)
+                .map(|properties| properties.iter().map(|p| guard_name(&p.value)).collect())
+                .unwrap_or_default(),
+            effective_guards: Vec::new(),
+            children: Vec::new(),
+            file: path.to_string(),
+            range: expr.range,
+        };
+
+        let mut effective = inherited.to_vec();
+        for guard in route.can_activate.iter().chain(&route.can_match) {
+            if !effective.contains(guard) {
+                effective.push(guard.clone());
+            }
+        }
+        route.effective_guards = effective.clone();
+        // 子ルートへの遷移では canActivateChild も実行される
+        for guard in &route.can_activate_child {
+            if !effective.contains(guard) {
+                effective.push(guard.clone());
+            }
+        }
+
+        if let Some(children) = get("children") {
+            route.children =
+                self.routes(path, module, children, &route.full_path, &effective, depth);
+        }
+        if let Some((specifier, export)) = get("loadChildren").and_then(import_target) {
+            route.load_children = Some(self.resolve(path, specifier));
+            if let Some((target, target_module, routes)) = self.lazy_routes(path, specifier, export)
+            {
+                route.children.extend(self.routes(
+                    &target,
+                    target_module,
+                    routes,
+                    &route.full_path,
+                    &effective,
+                    depth + 1,
+                ));
+            }
+        }
+        route
+    }
+
+    // loadChildren の読み込み先のルート配列
+    // (then で取り出す変数、NgModule なら同じディレクトリの forChild、それも無ければ Routes 型の変数)
+    fn lazy_routes(
+        &mut self,
+        from: &str,
+        specifier: &str,
+        export: Option<&str>,
+    ) -> Option<(String, &'a Module, &'a Expr)> {
+        let file = self.index.resolve_import(from, specifier)?;
+        let module = file.module()?;
+        if let Some(name) = export
+            && let Some(found) = self.variable(&file.path, module, name)
+        {
+            self.loaded.insert((found.0.clone(), name.to_string()));
+            return Some(found);
+        }
+
+        let dir = file.path.rsplit_once('/').map_or("", |(dir, _)| dir);
+        let siblings = self
+            .index
+            .files(FileKind::Ts)
+            .filter(|f| f.path.rsplit_once('/').map(|(d, _)| d) == Some(dir));
+        for sibling in std::iter::once(file).chain(siblings) {
+            let Some(sibling_module) = sibling.module() else {
+                continue;
+            };
+            if let Some(call) = sibling_module.calls_to("RouterModule.forChild").next()
+                && let Some(routes) = call.args.first()
+            {
+                if let Some(name) = routes.path() {
+                    self.loaded.insert((sibling.path.clone(), name));
+                }
+                return Some((sibling.path.clone(), sibling_module, routes));
+            }
+        }
+
+        let variable = module
+            .variables
+            .iter()
+            .find(|v| is_routes_type(v.type_annotation.as_deref()))?;
+        self.loaded
+            .insert((file.path.clone(), variable.name.clone()));
+        Some((file.path.clone(), module, variable.init.as_ref()?))
+    }
+
+    // 同じファイルの変数、または import した変数の初期値
+    fn variable(
+        &self,
+        path: &str,
+        module: &'a Module,
+        name: &str,
+    ) -> Option<(String, &'a Module, &'a Expr)> {
+        if let Some(init) = module.variable(name).and_then(|v| v.init.as_ref()) {
+            return Some((path.to_string(), module, init));
+        }
+        let import = module
+            .imports
+            .iter()
+            .find(|i| i.names.iter().any(|n| n == name))?;
+        let file = self.index.resolve_import(path, &import.source)?;
+        let target = file.module()?;
+        let init = target.variable(name)?.init.as_ref()?;
+        Some((file.path.clone(), target, init))
+    }
+
+    fn resolve(&self, from: &str, specifier: &str) -> String {
+        self.index
+            .resolve_import(from, specifier)
+            .map_or_else(|| specifier.to_string(), |f| f.path.clone())
+    }
+}
+
+// () => import('./x').then(m => m.Name) の指定子と取り出す名前
+fn import_target(expr: &Expr) -> Option<(&str, Option<&str>)> {
+    let mut specifier = None;
+    let mut export = None;
+    expr.walk(&mut |e| match &e.kind {
+        ExprKind::Import(source) => specifier = Some(source.as_str()),
+        ExprKind::Call { callee, args } if matches!(&callee.kind, ExprKind::Member { property, .. } if property == "then") => {
+            if let Some(ExprKind::Function { body: Some(body) }) = args.first().map(|a| &a.kind)
+                && let ExprKind::Member { property, .. } = &body.kind
+            {
+                export = Some(property.as_str());
+            }
+        }
+        _ => {}
+    });
+    Some((specifier?, export))
+}
+
+fn guard_names(expr: Option<&Expr>) -> Vec<String> {
+    expr.map(|e| e.items().iter().map(guard_name).collect())
+        .unwrap_or_default()
+}
+
+// authGuard / AuthGuard / roleGuard('admin') / () => ... の表記
+fn guard_name(expr: &Expr) -> String {
+    match &expr.kind {
+        ExprKind::Call { callee, .. } => callee.path(),
+        ExprKind::Function { .. } => Some(INLINE_FUNCTION.to_string()),
+        _ => expr.path(),
+    }
+    .unwrap_or_else(|| "?".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    fn paths(routes: &[Route]) -> Vec<String> {
+        let mut paths = Vec::new();
+        walk(routes, &mut |route, depth| {
+            paths.push(format!("{}{}", "  ".repeat(depth), route.full_path))
+        });
+        paths
+    }
+
+    fn find<'a>(routes: &'a [Route], full_path: &str) -> &'a Route {
+        let mut found = None;
+        walk(routes, &mut |route, _| {
+            if found.is_none() && route.full_path == full_path {
+                found = Some(route);
+            }
+        });
+        found.unwrap_or_else(|| panic!("{} が見つからない", full_path))
+    }
+
+    #[test]
+    fn tree_from_provide_router() {
+        let project = TempProject::new(&[
+            (
+                "src/main.ts",
+                "import { routes } from './app/app.routes';\nbootstrapApplication(App, { providers: [provideRouter(routes)] });\n",
+            ),
+            (
+                "src/app/app.routes.ts",
+                r#"import { Routes } from '@angular/router';
+import { extra } from './extra.routes';
+export const routes: Routes = [
+  { path: '', component: HomeComponent },
+  { path: 'users', children: [
+    { path: '', component: UserListComponent },
+    { path: ':id', component: UserDetailComponent },
+  ] },
+  ...extra,
+  { path: 'old', redirectTo: 'users' },
+  { path: '**', component: NotFoundComponent },
+];
+"#,
+            ),
+            (
+                "src/app/extra.routes.ts",
+                "export const extra: Routes = [{ path: 'about', loadComponent: () => import('./about.component').then(m => m.AboutComponent) }];\n",
+            ),
+            (
+                "src/app/about.component.ts",
+                "export class AboutComponent {}\n",
+            ),
+        ]);
+        let routes = collect_routes(&project.index());
+
+        assert_eq!(
+            paths(&routes),
+            [
+                "/",
+                "/users",
+                "  /users",
+                "  /users/:id",
+                "/about",
+                "/old",
+                "/**",
+            ]
+        );
+        let about = find(&routes, "/about");
+        assert!(about.is_lazy());
+        assert_eq!(
+            about.load_component.as_deref(),
+            Some(project.path("src/app/about.component.ts").as_str())
+        );
+        assert_eq!(about.file, project.path("src/app/extra.routes.ts"));
+        assert_eq!(find(&routes, "/old").redirect_to.as_deref(), Some("users"));
+        assert!(routes.last().unwrap().is_wildcard());
+        assert_eq!(
+            find(&routes, "/users/:id").component.as_deref(),
+            Some("UserDetailComponent")
+        );
+    }
+
+    #[test]
+    fn load_children_targets() {
+        let project = TempProject::new(&[
+            (
+                "src/app/app.module.ts",
+                r#"const routes: Routes = [
+  { path: 'admin', loadChildren: () => import('./admin/admin.routes').then(m => m.ADMIN_ROUTES) },
+  { path: 'legacy', loadChildren: () => import('./legacy/legacy.module').then(m => m.LegacyModule) },
+  { path: 'shop', loadChildren: () => import('./shop/shop.routes') },
+];
+@NgModule({ imports: [RouterModule.forRoot(routes)] })
+export class AppModule {}
+"#,
+            ),
+            (
+                "src/app/admin/admin.routes.ts",
+                "export const ADMIN_ROUTES: Routes = [{ path: 'settings', component: SettingsComponent }];\n",
+            ),
+            (
+                "src/app/legacy/legacy.module.ts",
+                "@NgModule({ imports: [LegacyRoutingModule] })\nexport class LegacyModule {}\n",
+            ),
+            (
+                "src/app/legacy/legacy-routing.module.ts",
+                "const routes: Routes = [{ path: 'list', component: ListComponent }];\n@NgModule({ imports: [RouterModule.forChild(routes)] })\nexport class LegacyRoutingModule {}\n",
+            ),
+            (
+                "src/app/shop/shop.routes.ts",
+                "const routes: Route[] = [{ path: 'cart', component: CartComponent }];\nexport default routes;\n",
+            ),
+        ]);
+        let routes = collect_routes(&project.index());
+
+        assert_eq!(
+            paths(&routes),
+            [
+                "/admin",
+                "  /admin/settings",
+                "/legacy",
+                "  /legacy/list",
+                "/shop",
+                "  /shop/cart",
+            ]
+        );
+        assert_eq!(
+            find(&routes, "/admin").load_children.as_deref(),
+            Some(project.path("src/app/admin/admin.routes.ts").as_str())
+        );
+        assert_eq!(
+            find(&routes, "/legacy/list").file,
+            project.path("src/app/legacy/legacy-routing.module.ts")
+        );
+    }
+
+    #[test]
+    fn guard_inheritance() {
+        let project = TempProject::new(&[(
+            "src/app/app.routes.ts",
+            r#"provideRouter([
+  {
+    path: 'admin',
+    canActivate: [authGuard],
+    canActivateChild: [AdminGuard],
+    canMatch: [roleGuard('admin')],
+    canDeactivate: [unsavedGuard],
+    resolve: { user: userResolver, config: () => inject(Config).load() },
+    children: [
+      { path: 'users', canActivate: [authGuard, () => true], component: UsersComponent },
+    ],
+  },
+  { path: 'public', canLoad: [FeatureGuard], component: PublicComponent },
+]);
+"#,
+        )]);
+        let routes = collect_routes(&project.index());
+
+        let admin = find(&routes, "/admin");
+        assert_eq!(admin.can_match, ["roleGuard"]);
+        assert_eq!(admin.can_deactivate, ["unsavedGuard"]);
+        assert_eq!(admin.resolvers, ["userResolver", INLINE_FUNCTION]);
+        // canActivateChild は子ルートにだけ効く
+        assert_eq!(admin.effective_guards, ["authGuard", "roleGuard"]);
+        let users = find(&routes, "/admin/users");
+        assert_eq!(
+            users.effective_guards,
+            ["authGuard", "roleGuard", "AdminGuard", INLINE_FUNCTION]
+        );
+        // canLoad は canMatch と同じ扱い
+        assert_eq!(find(&routes, "/public").effective_guards, ["FeatureGuard"]);
+    }
+
+    #[test]
+    fn fallback_without_router_setup() {
+        // forRoot が無いライブラリでは、他から読み込まれない Routes 変数が起点になる
+        let project = TempProject::new(&[
+            (
+                "lib/feature.routes.ts",
+                "import { CHILD_ROUTES } from './child.routes';\nexport const FEATURE_ROUTES: Routes = [{ path: 'feature', children: CHILD_ROUTES }];\n",
+            ),
+            (
+                "lib/child.routes.ts",
+                "export const CHILD_ROUTES: Routes = [{ path: 'child', component: ChildComponent }];\n",
+            ),
+        ]);
+        let routes = collect_routes(&project.index());
+        assert_eq!(paths(&routes), ["/feature", "  /feature/child"]);
+    }
+
+    #[test]
+    fn circular_load_children_stops() {
+        let project = TempProject::new(&[
+            (
+                "src/app.routes.ts",
+                "provideRouter([{ path: 'a', loadChildren: () => import('./a.routes').then(m => m.A) }]);\n",
+            ),
+            (
+                "src/a.routes.ts",
+                "export const A: Routes = [{ path: 'a', loadChildren: () => import('./a.routes').then(m => m.A) }];\n",
+            ),
+        ]);
+        let routes = collect_routes(&project.index());
+        let mut depth = 0;
+        walk(&routes, &mut |_, d| depth = depth.max(d));
+        assert!((MAX_DEPTH / 2..=MAX_DEPTH + 1).contains(&depth));
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+// 解析結果に添える一覧表 (コンポーネント一覧など)。全出力形式で表として出力する
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Table {
+    pub title: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(title: &str, columns: &[&str]) -> Self {
+        Self {
+            title: title.to_string(),
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    // 列数に満たない行は空文字で埋める
+    pub fn push_row(&mut self, row: Vec<String>) {
+        let mut row = row;
+        row.resize(self.columns.len().max(row.len()), String::new());
+        self.rows.push(row);
+    }
+}
@@ -0,0 +1,249 @@
+// Angular コンポーネントテンプレートの構文解析結果
+//
+// templateUrl の外部 HTML と template: のインラインテンプレートの両方を
+// 要素・属性 (バインディング)・補間・制御フローブロックの木に変換する。
+// 位置はテンプレートを含むファイル内のバイト範囲で保持する。
+
+mod parse;
+
+use crate::index::{ProjectIndex, resolve_path};
+use crate::ts::{ExprKind, TextRange};
+
+pub use parse::parse_template;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeKind {
+    // class="x"
+    Static,
+    // title="{{ name }}"
+    Interpolation,
+    // [value]="x" / bind-value="x"
+    Property,
+    // (click)="x" / on-click="x"
+    Event,
+    // [(ngModel)]="x" / bindon-ngModel="x"
+    TwoWay,
+    // *ngIf="x"
+    Structural,
+    // #ref / ref-ref
+    Reference,
+}
+
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    // ソース上の表記 (例: "[innerHTML]")
+    pub name: String,
+    pub kind: AttributeKind,
+    // 括弧などを除いた対象名 (例: "innerHTML", "attr.aria-label", "ngFor")
+    pub target: String,
+    pub value: Option<String>,
+    // 値の式で使われているパイプ名
+    pub pipes: Vec<String>,
+    pub range: TextRange,
+}
+
+impl Attribute {
+    // 値をプロパティに反映するバインディング ([x], [(x)], x="{{ }}")
+    pub fn is_property_binding(&self) -> bool {
+        matches!(
+            self.kind,
+            AttributeKind::Property | AttributeKind::TwoWay | AttributeKind::Interpolation
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<Attribute>,
+    pub children: Vec<Node>,
+    // 開始タグの位置
+    pub range: TextRange,
+}
+
+impl Element {
+    pub fn attribute(&self, name: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|a| a.name == name)
+    }
+
+    pub fn property_binding(&self, target: &str) -> Option<&Attribute> {
+        self.attributes
+            .iter()
+            .find(|a| a.is_property_binding() && a.target == target)
+    }
+
+    pub fn structural_directive(&self, target: &str) -> Option<&Attribute> {
+        self.attributes
+            .iter()
+            .find(|a| a.kind == AttributeKind::Structural && a.target == target)
+    }
+}
+
+// {{ expression }}
+#[derive(Debug, Clone)]
+pub struct Interpolation {
+    pub expression: String,
+    pub pipes: Vec<String>,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone)]
+pub struct Text {
+    pub value: String,
+    pub interpolations: Vec<Interpolation>,
+    pub range: TextRange,
+}
+
+// @if / @else / @for / @empty / @switch / @case / @defer / @placeholder / @let 等
+#[derive(Debug, Clone)]
+pub struct Block {
+    // "@" を除いた名前 (例: "if", "else if", "for", "defer")
+    pub name: String,
+    // 括弧内の式 (例: "item of items; track item.id")
+    pub parameters: Option<String>,
+    pub pipes: Vec<String>,
+    pub children: Vec<Node>,
+    // "@" から "{" (または @let の ";") までの位置
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone)]
+pub enum Node {
+    Element(Element),
+    Text(Text),
+    Block(Block),
+}
+
+impl Node {
+    pub fn children(&self) -> &[Node] {
+        match self {
+            Node::Element(element) => &element.children,
+            Node::Block(block) => &block.children,
+            Node::Text(_) => &[],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Template {
+    pub nodes: Vec<Node>,
+}
+
+impl Template {
+    // すべてのノードを深さ優先でたどる
+    pub fn walk<'a>(&'a self, f: &mut impl FnMut(&'a Node)) {
+        fn visit<'a>(nodes: &'a [Node], f: &mut impl FnMut(&'a Node)) {
+            for node in nodes {
+                f(node);
+                visit(node.children(), f);
+            }
+        }
+        visit(&self.nodes, f);
+    }
+
+    pub fn elements(&self) -> Vec<&Element> {
+        let mut elements = Vec::new();
+        self.walk(&mut |node| {
+            if let Node::Element(element) = node {
+                elements.push(element);
+            }
+        });
+        elements
+    }
+
+    pub fn blocks(&self) -> Vec<&Block> {
+        let mut blocks = Vec::new();
+        self.walk(&mut |node| {
+            if let Node::Block(block) = node {
+                blocks.push(block);
+            }
+        });
+        blocks
+    }
+
+    // テンプレート全体で使われているパイプ名 (重複あり)
+    pub fn pipes(&self) -> Vec<&str> {
+        let mut pipes = Vec::new();
+        self.walk(&mut |node| match node {
+            Node::Element(element) => {
+                for attribute in &element.attributes {
+                    pipes.extend(attribute.pipes.iter().map(String::as_str));
+                }
+            }
+            Node::Text(text) => {
+                for interpolation in &text.interpolations {
+                    pipes.extend(interpolation.pipes.iter().map(String::as_str));
+                }
+            }
+            Node::Block(block) => pipes.extend(block.pipes.iter().map(String::as_str)),
+        });
+        pipes
+    }
+}
+
+// @Component クラスに対応するテンプレート
+#[derive(Debug, Clone)]
+pub struct ComponentTemplate {
+    pub component: String,
+    pub component_file: String,
+    // テンプレートのソースを含むファイル (インラインならコンポーネント自身)
+    pub file: String,
+    pub inline: bool,
+    // templateUrl のファイルが存在しない場合は None
+    pub template: Option<Template>,
+    // コンポーネント側の template / templateUrl プロパティの位置
+    pub range: TextRange,
+}
+
+// プロジェクト内の全コンポーネントのテンプレートを解析する
+pub fn collect_templates(index: &ProjectIndex) -> Vec<ComponentTemplate> {
+    let mut templates = Vec::new();
+    for file in index.files(crate::index::FileKind::Ts) {
+        let (Some(content), Some(module)) = (file.content(), file.module()) else {
+            continue;
+        };
+        for class in module.decorated_classes("Component") {
+            let Some(metadata) = class.decorator("Component").and_then(|d| d.metadata()) else {
+                continue;
+            };
+            for property in metadata {
+                let value = &property.value;
+                match (property.key.as_str(), &value.kind) {
+                    ("template", ExprKind::Str(_) | ExprKind::Template(_)) => {
+                        // クォートを除いた生のソースを解析し、位置をファイル内に合わせる
+                        let start = value.range.start + 1;
+                        let end = value.range.end.saturating_sub(1).max(start);
+                        templates.push(ComponentTemplate {
+                            component: class.name.clone(),
+                            component_file: file.path.clone(),
+                            file: file.path.clone(),
+                            inline: true,
+                            template: Some(parse_template(&content[start..end], start)),
+                            range: property.range,
+                        });
+                    }
+                    ("templateUrl", _) => {
+                        let Some(url) = value.as_str() else {
+                            continue;
+                        };
+                        let path = resolve_path(&file.path, url);
+                        let template = index
+                            .get(&path)
+                            .and_then(|f| f.content())
+                            .map(|html| parse_template(html, 0));
+                        templates.push(ComponentTemplate {
+                            component: class.name.clone(),
+                            component_file: file.path.clone(),
+                            file: path,
+                            inline: false,
+                            template,
+                            range: property.range,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    templates
+}
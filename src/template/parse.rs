@@ -0,0 +1,594 @@
+use super::{Attribute, AttributeKind, Block, Element, Interpolation, Node, Template, Text};
+use crate::ts::TextRange;
+
+// 終了タグを持たない要素
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+// 中身をテキストとして扱う要素
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+// テンプレートを解析する。base はテンプレートを含むファイル内での開始オフセット
+//
+// ブラウザの HTML パーサーほど厳密ではなく、閉じ忘れや対応しない終了タグは
+// 読み飛ばして解析を続ける。
+pub fn parse_template(source: &str, base: usize) -> Template {
+    let mut parser = Parser {
+        src: source,
+        pos: 0,
+        base,
+        root: Vec::new(),
+        stack: Vec::new(),
+    };
+    parser.run();
+    while !parser.stack.is_empty() {
+        parser.close_top();
+    }
+    Template { nodes: parser.root }
+}
+
+struct Parser<'s> {
+    src: &'s str,
+    pos: usize,
+    base: usize,
+    root: Vec<Node>,
+    // 閉じていない要素・ブロック
+    stack: Vec<Node>,
+}
+
+impl Parser<'_> {
+    fn run(&mut self) {
+        while self.pos < self.src.len() {
+            let rest = &self.src[self.pos..];
+            if rest.starts_with("<!--") {
+                self.pos =
+                    find_from(self.src, self.pos + 4, "-->").map_or(self.src.len(), |i| i + 3);
+            } else if rest.starts_with("</") {
+                self.parse_end_tag();
+            } else if rest.starts_with("<!") {
+                self.pos = find_from(self.src, self.pos, ">").map_or(self.src.len(), |i| i + 1);
+            } else if rest.starts_with('<') && next_is_alpha(rest, 1) {
+                self.parse_start_tag();
+            } else if rest.starts_with('@') && next_is_alpha(rest, 1) {
+                self.parse_block();
+            } else if rest.starts_with('}') && self.in_block() {
+                self.pos += 1;
+                self.close_block();
+            } else {
+                self.parse_text();
+            }
+        }
+    }
+
+    fn range(&self, start: usize, end: usize) -> TextRange {
+        TextRange {
+            start: self.base + start,
+            end: self.base + end,
+        }
+    }
+
+    fn in_block(&self) -> bool {
+        self.stack.iter().any(|node| matches!(node, Node::Block(_)))
+    }
+
+    fn push_node(&mut self, node: Node) {
+        match self.stack.last_mut() {
+            Some(Node::Element(parent)) => parent.children.push(node),
+            Some(Node::Block(parent)) => parent.children.push(node),
+            _ => self.root.push(node),
+        }
+    }
+
+    fn close_top(&mut self) {
+        if let Some(node) = self.stack.pop() {
+            self.push_node(node);
+        }
+    }
+
+    // 対応する開始タグまでの閉じ忘れた要素もまとめて閉じる。ブロックは越えない
+    fn close_element(&mut self, name: &str) {
+        let found = self.stack.iter().rposition(|node| match node {
+            Node::Element(element) => element.name.eq_ignore_ascii_case(name),
+            _ => false,
+        });
+        let Some(index) = found else {
+            return;
+        };
+        if self.stack[index..]
+            .iter()
+            .any(|node| matches!(node, Node::Block(_)))
+        {
+            return;
+        }
+        while self.stack.len() > index {
+            self.close_top();
+        }
+    }
+
+    fn close_block(&mut self) {
+        while let Some(node) = self.stack.last() {
+            let is_block = matches!(node, Node::Block(_));
+            self.close_top();
+            if is_block {
+                break;
+            }
+        }
+    }
+
+    fn parse_end_tag(&mut self) {
+        let name_start = self.pos + 2;
+        let end = find_from(self.src, name_start, ">").unwrap_or(self.src.len());
+        let name = self.src[name_start..end].trim().to_string();
+        self.pos = (end + 1).min(self.src.len());
+        self.close_element(&name);
+    }
+
+    fn parse_start_tag(&mut self) {
+        let start = self.pos;
+        self.pos += 1;
+        let name = self.take_while(|c| !c.is_ascii_whitespace() && c != '>' && c != '/');
+
+        let mut attributes = Vec::new();
+        let mut self_closing = false;
+        loop {
+            self.skip_whitespace();
+            let rest = &self.src[self.pos..];
+            if rest.is_empty() {
+                break;
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                self_closing = true;
+                break;
+            }
+            if rest.starts_with('/') {
+                self.pos += 1;
+                continue;
+            }
+            attributes.push(self.parse_attribute());
+        }
+
+        let element = Element {
+            name: name.clone(),
+            attributes,
+            children: Vec::new(),
+            range: self.range(start, self.pos),
+        };
+        let lower = name.to_ascii_lowercase();
+        if self_closing || VOID_ELEMENTS.contains(&lower.as_str()) {
+            self.push_node(Node::Element(element));
+        } else if RAW_TEXT_ELEMENTS.contains(&lower.as_str()) {
+            let close = format!("</{}", lower);
+            let end = find_from(&self.src.to_ascii_lowercase(), self.pos, &close)
+                .unwrap_or(self.src.len());
+            self.pos = find_from(self.src, end, ">").map_or(self.src.len(), |i| i + 1);
+            self.push_node(Node::Element(element));
+        } else {
+            self.stack.push(Node::Element(element));
+        }
+    }
+
+    fn parse_attribute(&mut self) -> Attribute {
+        let start = self.pos;
+        let mut depth = 0i32;
+        let name = self.take_while(|c| {
+            match c {
+                '[' | '(' => depth += 1,
+                ']' | ')' => depth -= 1,
+                _ => {}
+            }
+            depth > 0 || !(c.is_ascii_whitespace() || c == '=' || c == '>' || c == '/')
+        });
+
+        self.skip_whitespace();
+        let mut value = None;
+        if self.src[self.pos..].starts_with('=') {
+            self.pos += 1;
+            self.skip_whitespace();
+            let rest = &self.src[self.pos..];
+            if let Some(quote) = rest.chars().next().filter(|&c| c == '"' || c == '\'') {
+                let value_start = self.pos + 1;
+                let end = self.src[value_start..]
+                    .find(quote)
+                    .map_or(self.src.len(), |i| value_start + i);
+                value = Some(self.src[value_start..end].to_string());
+                self.pos = (end + 1).min(self.src.len());
+            } else {
+                value = Some(self.take_while(|c| !c.is_ascii_whitespace() && c != '>'));
+            }
+        }
+        // 属性名が読めない不正な文字は 1 文字読み飛ばす
+        if self.pos == start {
+            self.pos += self.src[start..].chars().next().map_or(1, char::len_utf8);
+        }
+
+        let (kind, target) = classify_attribute(&name, value.as_deref());
+        let pipes = match (kind, value.as_deref()) {
+            (AttributeKind::Interpolation, Some(value)) => interpolations(value)
+                .into_iter()
+                .flat_map(|(_, _, expression)| pipe_names(expression))
+                .collect(),
+            (
+                AttributeKind::Property | AttributeKind::TwoWay | AttributeKind::Structural,
+                Some(value),
+            ) => pipe_names(value),
+            _ => Vec::new(),
+        };
+
+        Attribute {
+            name,
+            kind,
+            target,
+            value,
+            pipes,
+            range: self.range(start, self.pos),
+        }
+    }
+
+    fn parse_block(&mut self) {
+        let start = self.pos;
+        self.pos += 1;
+        let mut name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+        if name == "else" {
+            let save = self.pos;
+            self.skip_whitespace();
+            if self.src[self.pos..].starts_with("if") && !next_is_alpha(&self.src[self.pos..], 2) {
+                self.pos += 2;
+                name.push_str(" if");
+            } else {
+                self.pos = save;
+            }
+        }
+
+        // @let name = expr;
+        if name == "let" {
+            let end = find_from(self.src, self.pos, ";").unwrap_or(self.src.len());
+            let parameters = self.src[self.pos..end].trim().to_string();
+            self.pos = (end + 1).min(self.src.len());
+            let block = Block {
+                name,
+                pipes: pipe_names(&parameters),
+                parameters: Some(parameters),
+                children: Vec::new(),
+                range: self.range(start, self.pos),
+            };
+            self.push_node(Node::Block(block));
+            return;
+        }
+
+        self.skip_whitespace();
+        let mut parameters = None;
+        if self.src[self.pos..].starts_with('(') {
+            let end = matching_paren(self.src, self.pos).unwrap_or(self.src.len());
+            parameters = Some(self.src[self.pos + 1..end].trim().to_string());
+            self.pos = (end + 1).min(self.src.len());
+            self.skip_whitespace();
+        }
+
+        if !self.src[self.pos..].starts_with('{') {
+            // ブロックではない "@" はテキストとして扱う
+            self.pos = start;
+            self.parse_text_from(start + 1);
+            return;
+        }
+        self.pos += 1;
+
+        let block = Block {
+            name,
+            pipes: parameters.as_deref().map(pipe_names).unwrap_or_default(),
+            parameters,
+            children: Vec::new(),
+            range: self.range(start, self.pos),
+        };
+        self.stack.push(Node::Block(block));
+    }
+
+    fn parse_text(&mut self) {
+        self.parse_text_from(self.pos + 1);
+    }
+
+    // start から次のタグ・ブロックの開始までをテキストとして読む
+    fn parse_text_from(&mut self, scan_from: usize) {
+        let start = self.pos;
+        let in_block = self.in_block();
+        // 先頭の "{{" は補間として読み、それ以外の先頭文字は必ず 1 文字消費する
+        let mut i = if self.src[start..].starts_with("{{") {
+            start
+        } else {
+            scan_from.min(self.src.len())
+        };
+        while !self.src.is_char_boundary(i) {
+            i += 1;
+        }
+        let bytes = self.src.as_bytes();
+        while i < bytes.len() {
+            let rest = &self.src[i..];
+            if rest.starts_with("{{") {
+                i = find_from(self.src, i + 2, "}}").map_or(self.src.len(), |j| j + 2);
+                continue;
+            }
+            let stop = match bytes[i] {
+                b'<' => next_is_alpha(rest, 1) || rest.starts_with("</") || rest.starts_with("<!"),
+                b'@' => next_is_alpha(rest, 1),
+                b'}' => in_block,
+                _ => false,
+            };
+            if stop {
+                break;
+            }
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+        self.pos = i;
+
+        let value = &self.src[start..i];
+        if value.trim().is_empty() {
+            return;
+        }
+        let interpolations = interpolations(value)
+            .into_iter()
+            .map(|(s, e, expression)| Interpolation {
+                expression: expression.trim().to_string(),
+                pipes: pipe_names(expression),
+                range: self.range(start + s, start + e),
+            })
+            .collect();
+        let text = Text {
+            value: value.to_string(),
+            interpolations,
+            range: self.range(start, i),
+        };
+        self.push_node(Node::Text(text));
+    }
+
+    fn take_while(&mut self, mut pred: impl FnMut(char) -> bool) -> String {
+        let start = self.pos;
+        for c in self.src[start..].chars() {
+            if !pred(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+        self.src[start..self.pos].to_string()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(|c| c.is_whitespace());
+    }
+}
+
+fn find_from(src: &str, from: usize, pattern: &str) -> Option<usize> {
+    src.get(from..)?.find(pattern).map(|i| from + i)
+}
+
+fn next_is_alpha(rest: &str, offset: usize) -> bool {
+    rest.as_bytes()
+        .get(offset)
+        .is_some_and(|b| b.is_ascii_alphabetic())
+}
+
+// start の "(" に対応する ")" の位置 (文字列リテラル内は無視)
+fn matching_paren(src: &str, start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in src[start..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn classify_attribute(name: &str, value: Option<&str>) -> (AttributeKind, String) {
+    let strip = |prefix: &str, suffix: &str| {
+        name.strip_prefix(prefix)
+            .and_then(|n| n.strip_suffix(suffix))
+            .map(str::to_string)
+    };
+    if let Some(target) = strip("[(", ")]").or_else(|| strip("bindon-", "")) {
+        (AttributeKind::TwoWay, target)
+    } else if let Some(target) = strip("[", "]").or_else(|| strip("bind-", "")) {
+        (AttributeKind::Property, target)
+    } else if let Some(target) = strip("(", ")").or_else(|| strip("on-", "")) {
+        (AttributeKind::Event, target)
+    } else if let Some(target) = strip("*", "") {
+        (AttributeKind::Structural, target)
+    } else if let Some(target) = strip("#", "").or_else(|| strip("ref-", "")) {
+        (AttributeKind::Reference, target)
+    } else if value.is_some_and(|v| v.contains("{{")) {
+        (AttributeKind::Interpolation, name.to_string())
+    } else {
+        (AttributeKind::Static, name.to_string())
+    }
+}
+
+// text 内の {{ }} の (開始, 終了, 中の式)
+fn interpolations(text: &str) -> Vec<(usize, usize, &str)> {
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(start) = find_from(text, from, "{{") {
+        let Some(end) = find_from(text, start + 2, "}}") else {
+            break;
+        };
+        found.push((start, end + 2, &text[start + 2..end]));
+        from = end + 2;
+    }
+    found
+}
+
+// 式中のパイプ名 (例: "items | async | slice:0:3" → ["async", "slice"])
+fn pipe_names(expression: &str) -> Vec<String> {
+    let mut pipes = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let chars: Vec<char> = expression.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            (None, '|') if depth == 0 => {
+                if chars.get(i + 1) == Some(&'|') {
+                    i += 2;
+                    continue;
+                }
+                let name: String = chars[i + 1..]
+                    .iter()
+                    .skip_while(|c| c.is_whitespace())
+                    .take_while(|c| c.is_alphanumeric() || **c == '_' || **c == '$')
+                    .collect();
+                if !name.is_empty() {
+                    pipes.push(name);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    pipes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(template: &Template) -> Vec<&str> {
+        let mut texts = Vec::new();
+        template.walk(&mut |node| {
+            if let Node::Text(text) = node {
+                texts.push(text.value.trim());
+            }
+        });
+        texts
+    }
+
+    #[test]
+    fn multibyte_text() {
+        let template = parse_template("<h1>ようこそ</h1><p>こんにちは {{ name }} さん</p>", 0);
+        assert_eq!(texts(&template), ["ようこそ", "こんにちは {{ name }} さん"]);
+        assert_eq!(template.nodes.len(), 2);
+    }
+
+    #[test]
+    fn multibyte_text_before_tag_and_block() {
+        let source = "é<b>x</b>日本@if (a) {語}";
+        let template = parse_template(source, 0);
+        assert_eq!(texts(&template), ["é", "x", "日本", "語"]);
+        assert_eq!(template.blocks()[0].name, "if");
+    }
+
+    #[test]
+    fn interpolation_pipes_and_ranges() {
+        let source = "<p>{{ items | async | slice:0:3 }} / {{ a || b }}</p>";
+        let template = parse_template(source, 10);
+        let Node::Element(p) = &template.nodes[0] else {
+            panic!("expected element");
+        };
+        let Node::Text(text) = &p.children[0] else {
+            panic!("expected text");
+        };
+        assert_eq!(text.interpolations.len(), 2);
+        assert_eq!(
+            text.interpolations[0].expression,
+            "items | async | slice:0:3"
+        );
+        assert_eq!(text.interpolations[0].pipes, ["async", "slice"]);
+        assert!(text.interpolations[1].pipes.is_empty());
+        let range = text.interpolations[0].range;
+        assert_eq!(
+            &source[range.start - 10..range.end - 10],
+            "{{ items | async | slice:0:3 }}"
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        let template = parse_template(
+            r#"<input [value]="v | date" (input)="on()" [(ngModel)]="m" *ngIf="ok" #ref title="{{ t }}" disabled>"#,
+            0,
+        );
+        let input = template.elements()[0];
+        let kinds: Vec<_> = input
+            .attributes
+            .iter()
+            .map(|a| (a.kind, a.target.as_str()))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (AttributeKind::Property, "value"),
+                (AttributeKind::Event, "input"),
+                (AttributeKind::TwoWay, "ngModel"),
+                (AttributeKind::Structural, "ngIf"),
+                (AttributeKind::Reference, "ref"),
+                (AttributeKind::Interpolation, "title"),
+                (AttributeKind::Static, "disabled"),
+            ]
+        );
+        assert_eq!(input.attributes[0].pipes, ["date"]);
+        assert!(input.children.is_empty());
+    }
+
+    #[test]
+    fn control_flow_blocks() {
+        let template = parse_template(
+            "@if (user) { <b>{{ user.name }}</b> } @else if (x) { a } @else { b }\n\
+             @for (item of items | async; track item.id) { <li>{{ item }}</li> } @empty { none }\n\
+             @let total = price * 2;\n\
+             user@example.com",
+            0,
+        );
+        let blocks: Vec<_> = template.blocks().iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(blocks, ["if", "else if", "else", "for", "empty", "let"]);
+        let for_block = template.blocks()[3];
+        assert_eq!(
+            for_block.parameters.as_deref(),
+            Some("item of items | async; track item.id")
+        );
+        assert_eq!(for_block.pipes, ["async"]);
+        assert_eq!(for_block.children.len(), 1);
+        // ブロックにならない "@" はテキストのまま残る
+        assert!(texts(&template).concat().ends_with("user@example.com"));
+    }
+
+    #[test]
+    fn unclosed_and_stray_tags() {
+        let template = parse_template("<div><span>a</div></p><ul><li>b", 0);
+        assert_eq!(template.nodes.len(), 2);
+        let Node::Element(div) = &template.nodes[0] else {
+            panic!("expected element");
+        };
+        assert_eq!(div.children.len(), 1);
+        assert_eq!(texts(&template), ["a", "b"]);
+    }
+
+    #[test]
+    fn unclosed_block_and_comment() {
+        let template = parse_template(
+            "@if (a) { <p>x</p> <!-- } --> <script>if (a) { }</script>",
+            0,
+        );
+        let blocks = template.blocks();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].children.len(), 2);
+    }
+}
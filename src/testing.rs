@@ -0,0 +1,46 @@
+// テスト用の一時プロジェクト (ファイルの内容をその場で書き、破棄時に削除する)
+
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::index::ProjectIndex;
+
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+pub struct TempProject {
+    pub root: String,
+}
+
+impl TempProject {
+    // files は (ルートからの相対パス, 内容)
+    pub fn new(files: &[(&str, &str)]) -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "angular-analyzer-test-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let root = dir.to_str().unwrap().to_string();
+        for (relative, content) in files {
+            let path = dir.join(relative);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+        Self { root }
+    }
+
+    pub fn path(&self, relative: &str) -> String {
+        format!("{}/{}", self.root, relative)
+    }
+
+    pub fn index(&self) -> ProjectIndex {
+        ProjectIndex::build(&self.root).unwrap()
+    }
+}
+
+impl Drop for TempProject {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(Path::new(&self.root));
+    }
+}
@@ -0,0 +1,368 @@
+// TypeScript ソースの構文解析結果
+//
+// oxc の AST はアロケータに束縛されるため、解析器が必要とする構造
+// (import、クラスとデコレーター、呼び出し、プロパティアクセス等) だけを
+// 所有型に変換して保持する。コメントや文字列の中身は識別子として扱わない。
+
+mod parse;
+
+use std::collections::BTreeSet;
+
+pub use parse::parse_module;
+
+// ソース内のバイト範囲
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    // import ... from '...'
+    Static,
+    // export ... from '...'
+    ReExport,
+    // import('...')
+    Dynamic,
+}
+
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub source: String,
+    // 取り込む名前 (名前空間 import は "*"、default は "default")
+    pub names: Vec<String>,
+    pub kind: ImportKind,
+    pub type_only: bool,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone)]
+pub struct Decorator {
+    pub name: String,
+    pub args: Vec<Expr>,
+    pub range: TextRange,
+}
+
+impl Decorator {
+    // @Component({...}) のような最初の引数のオブジェクトリテラル
+    pub fn metadata(&self) -> Option<&[Property]> {
+        self.args.first().and_then(Expr::as_object)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberKind {
+    Property,
+    Method,
+    Constructor,
+    Getter,
+    Setter,
+}
+
+#[derive(Debug, Clone)]
+pub struct Param {
+    pub name: String,
+    pub type_annotation: Option<String>,
+    // private http: HttpClient のようなパラメータプロパティ
+    pub accessibility: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClassMember {
+    pub name: String,
+    pub kind: MemberKind,
+    pub decorators: Vec<Decorator>,
+    pub type_annotation: Option<String>,
+    pub value: Option<Expr>,
+    pub params: Vec<Param>,
+    // public / protected / private (省略時は None)
+    pub accessibility: Option<String>,
+    pub is_static: bool,
+    pub is_readonly: bool,
+    pub range: TextRange,
+}
+
+impl ClassMember {
+    pub fn is_public(&self) -> bool {
+        !self.name.starts_with('#') && self.accessibility.as_deref().is_none_or(|a| a == "public")
+    }
+
+    pub fn decorator(&self, name: &str) -> Option<&Decorator> {
+        self.decorators.iter().find(|d| d.name == name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Class {
+    pub name: String,
+    pub decorators: Vec<Decorator>,
+    pub extends: Option<String>,
+    pub implements: Vec<String>,
+    pub members: Vec<ClassMember>,
+    pub exported: bool,
+    pub range: TextRange,
+}
+
+impl Class {
+    pub fn decorator(&self, name: &str) -> Option<&Decorator> {
+        self.decorators.iter().find(|d| d.name == name)
+    }
+
+    pub fn member(&self, name: &str) -> Option<&ClassMember> {
+        self.members.iter().find(|m| m.name == name)
+    }
+
+    pub fn implements(&self, name: &str) -> bool {
+        self.implements.iter().any(|i| i == name)
+    }
+}
+
+// トップレベルの変数宣言
+#[derive(Debug, Clone)]
+pub struct Variable {
+    pub name: String,
+    pub type_annotation: Option<String>,
+    pub init: Option<Expr>,
+    pub exported: bool,
+    pub range: TextRange,
+}
+
+// トップレベルの関数宣言
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub return_type: Option<String>,
+    pub exported: bool,
+    pub range: TextRange,
+}
+
+// 呼び出し位置を含むクラス・メンバー
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scope {
+    pub class: Option<String>,
+    pub member: Option<String>,
+    pub member_kind: Option<MemberKind>,
+    // トップレベル関数の中であればその名前
+    pub function: Option<String>,
+    // メンバー (フィールドの初期化子を含む)・トップレベル関数の本体の中にある
+    // 関数式 (アロー関数・コールバック等) の中か
+    pub in_closure: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Call {
+    // 呼び出し先の表記 (例: "this.http.get", "interval().subscribe")
+    pub callee: String,
+    pub args: Vec<Expr>,
+    pub scope: Scope,
+    // この呼び出しを引数の中に含む呼び出し (外側から順、Module::calls の添字)
+    pub enclosing_calls: Vec<usize>,
+    // 呼び出しの結果を代入・初期化した先 (例: "this.sub = x.subscribe()" → "this.sub",
+    // "const sub = x.subscribe()" → "sub"、フィールドの初期化子は "this.フィールド名")
+    pub assigned_to: Option<String>,
+    pub range: TextRange,
+}
+
+impl Call {
+    // 最後のメソッド名 (例: "this.http.get" → "get")
+    pub fn method(&self) -> &str {
+        self.callee.rsplit('.').next().unwrap_or(&self.callee)
+    }
+
+    // メソッド呼び出しのレシーバー (例: "this.http.get" → "this.http")
+    pub fn receiver(&self) -> Option<&str> {
+        self.callee.rsplit_once('.').map(|(receiver, _)| receiver)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemberAccess {
+    pub object: String,
+    pub property: String,
+    pub scope: Scope,
+    // プロパティ名の位置
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone)]
+pub struct StringLiteral {
+    pub value: String,
+    pub range: TextRange,
+}
+
+// 1ファイル分の解析結果
+#[derive(Debug, Clone, Default)]
+pub struct Module {
+    pub imports: Vec<Import>,
+    pub classes: Vec<Class>,
+    pub variables: Vec<Variable>,
+    pub functions: Vec<Function>,
+    pub calls: Vec<Call>,
+    pub members: Vec<MemberAccess>,
+    pub strings: Vec<StringLiteral>,
+    // コード中に現れる識別子・プロパティ名・宣言名
+    pub names: BTreeSet<String>,
+    pub try_blocks: Vec<TextRange>,
+}
+
+impl Module {
+    pub fn has_name(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    pub fn any_name(&self, pred: impl Fn(&str) -> bool) -> bool {
+        self.names.iter().any(|n| pred(n))
+    }
+
+    pub fn imports_from<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a Import> {
+        self.imports
+            .iter()
+            .filter(move |i| i.source == prefix || i.source.starts_with(prefix))
+    }
+
+    pub fn imports_name(&self, source: &str, name: &str) -> bool {
+        self.imports
+            .iter()
+            .any(|i| i.source == source && i.names.iter().any(|n| n == name))
+    }
+
+    pub fn calls_to<'a>(&'a self, callee: &'a str) -> impl Iterator<Item = &'a Call> {
+        self.calls.iter().filter(move |c| c.callee == callee)
+    }
+
+    pub fn method_calls<'a>(&'a self, method: &'a str) -> impl Iterator<Item = &'a Call> {
+        self.calls
+            .iter()
+            .filter(move |c| c.receiver().is_some() && c.method() == method)
+    }
+
+    pub fn member_accesses<'a>(
+        &'a self,
+        property: &'a str,
+    ) -> impl Iterator<Item = &'a MemberAccess> {
+        self.members.iter().filter(move |m| m.property == property)
+    }
+
+    pub fn decorated_classes<'a>(&'a self, decorator: &'a str) -> impl Iterator<Item = &'a Class> {
+        self.classes
+            .iter()
+            .filter(move |c| c.decorator(decorator).is_some())
+    }
+
+    pub fn variable(&self, name: &str) -> Option<&Variable> {
+        self.variables.iter().find(|v| v.name == name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Property {
+    pub key: String,
+    pub value: Expr,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone)]
+pub enum ExprKind {
+    Object(Vec<Property>),
+    Array(Vec<Expr>),
+    Str(String),
+    // テンプレートリテラルの中身 (バッククォートを除いたソースそのまま)
+    Template(String),
+    Num(f64),
+    Bool(bool),
+    Null,
+    Ident(String),
+    This,
+    Member { object: Box<Expr>, property: String },
+    Call { callee: Box<Expr>, args: Vec<Expr> },
+    New { callee: Box<Expr>, args: Vec<Expr> },
+    // import('...')
+    Import(String),
+    // アロー関数・関数式。本体が単一の式 (または return 文のみ) の場合はその式
+    Function { body: Option<Box<Expr>> },
+    Spread(Box<Expr>),
+    Other,
+}
+
+impl Expr {
+    pub fn as_object(&self) -> Option<&[Property]> {
+        match &self.kind {
+            ExprKind::Object(props) => Some(props),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Expr]> {
+        match &self.kind {
+            ExprKind::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    // 文字列リテラル、または式を含まないテンプレートリテラル
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.kind {
+            ExprKind::Str(s) => Some(s),
+            ExprKind::Template(s) if !s.contains("${") => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.kind {
+            ExprKind::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    // オブジェクトリテラルのプロパティ
+    pub fn get(&self, key: &str) -> Option<&Expr> {
+        self.as_object()?
+            .iter()
+            .find(|p| p.key == key)
+            .map(|p| &p.value)
+    }
+
+    // 識別子とプロパティアクセスの連鎖 (例: "ChangeDetectionStrategy.OnPush")
+    pub fn path(&self) -> Option<String> {
+        match &self.kind {
+            ExprKind::Ident(name) => Some(name.clone()),
+            ExprKind::This => Some("this".to_string()),
+            ExprKind::Member { object, property } => {
+                Some(format!("{}.{}", object.path()?, property))
+            }
+            _ => None,
+        }
+    }
+
+    // 配列なら各要素、それ以外は自身
+    pub fn items(&self) -> &[Expr] {
+        match &self.kind {
+            ExprKind::Array(items) => items,
+            _ => std::slice::from_ref(self),
+        }
+    }
+
+    // 子孫を含むすべての式を深さ優先でたどる
+    pub fn walk<'a>(&'a self, f: &mut impl FnMut(&'a Expr)) {
+        f(self);
+        match &self.kind {
+            ExprKind::Object(props) => props.iter().for_each(|p| p.value.walk(f)),
+            ExprKind::Array(items) => items.iter().for_each(|e| e.walk(f)),
+            ExprKind::Member { object, .. } => object.walk(f),
+            ExprKind::Call { callee, args } | ExprKind::New { callee, args } => {
+                callee.walk(f);
+                args.iter().for_each(|e| e.walk(f));
+            }
+            ExprKind::Function { body: Some(body) } | ExprKind::Spread(body) => body.walk(f),
+            _ => {}
+        }
+    }
+}
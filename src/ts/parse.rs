@@ -0,0 +1,987 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    self, Argument, ArrayExpressionElement, ChainElement, ClassElement, Declaration,
+    ExportDefaultDeclarationKind, Expression, ImportDeclarationSpecifier, MethodDefinitionKind,
+    ObjectPropertyKind, PropertyKey, Statement, TSAccessibility,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_syntax::scope::ScopeFlags;
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    Call, Class, ClassMember, Decorator, Expr, ExprKind, Function, Import, ImportKind,
+    MemberAccess, MemberKind, Module, Param, Property, Scope, StringLiteral, TextRange, Variable,
+};
+
+// 深くネストした式はここで打ち切る (スタック溢れ防止)
+const MAX_EXPR_DEPTH: usize = 64;
+
+// 構文エラーで解析を継続できなかった場合は None
+pub fn parse_module(path: &str, source: &str) -> Option<Module> {
+    let source_type = SourceType::from_path(path).unwrap_or(SourceType::ts());
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    if ret.panicked {
+        return None;
+    }
+
+    let mut collector = Collector {
+        source,
+        module: Module::default(),
+        exported: HashSet::new(),
+        class_stack: Vec::new(),
+        member_stack: Vec::new(),
+        function_stack: Vec::new(),
+        call_stack: Vec::new(),
+        function_depth: 0,
+        scope_depth: 0,
+        assignments: HashMap::new(),
+    };
+    collector.collect_top_level(&ret.program.body);
+    collector.visit_program(&ret.program);
+
+    let Collector {
+        mut module,
+        exported,
+        ..
+    } = collector;
+    for class in &mut module.classes {
+        class.exported = exported.contains(&class.name);
+    }
+    Some(module)
+}
+
+struct Collector<'s> {
+    source: &'s str,
+    module: Module,
+    exported: HashSet<String>,
+    class_stack: Vec<String>,
+    member_stack: Vec<(String, MemberKind)>,
+    function_stack: Vec<String>,
+    call_stack: Vec<usize>,
+    // 入れ子になった関数 (メソッド・アロー関数を含む) の深さと、現在のメンバー・トップレベル関数の
+    // 本体の深さ (これより深ければ本体の中の関数式の中)
+    function_depth: usize,
+    scope_depth: usize,
+    // 代入・初期化の右辺そのものである呼び出し (span の開始・終了) とその代入先
+    assignments: HashMap<(u32, u32), String>,
+}
+
+impl Collector<'_> {
+    // トップレベルの変数・関数宣言と export された名前
+    fn collect_top_level(&mut self, body: &[Statement<'_>]) {
+        for stmt in body {
+            match stmt {
+                Statement::VariableDeclaration(decl) => self.add_variables(decl, false),
+                Statement::FunctionDeclaration(func) => self.add_function(func, false),
+                Statement::ExportNamedDeclaration(export) => {
+                    match &export.declaration {
+                        Some(Declaration::VariableDeclaration(decl)) => {
+                            self.add_variables(decl, true)
+                        }
+                        Some(Declaration::FunctionDeclaration(func)) => {
+                            self.add_function(func, true)
+                        }
+                        Some(Declaration::ClassDeclaration(class)) => {
+                            if let Some(id) = &class.id {
+                                self.exported.insert(id.name.to_string());
+                            }
+                        }
+                        _ => {}
+                    }
+                    for spec in &export.specifiers {
+                        self.exported.insert(spec.local.name().to_string());
+                    }
+                }
+                Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                        if let Some(id) = &class.id {
+                            self.exported.insert(id.name.to_string());
+                        }
+                    }
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                        self.add_function(func, true)
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        for variable in &mut self.module.variables {
+            variable.exported |= self.exported.contains(&variable.name);
+        }
+        for function in &mut self.module.functions {
+            function.exported |= self.exported.contains(&function.name);
+        }
+    }
+
+    fn add_variables(&mut self, decl: &ast::VariableDeclaration<'_>, exported: bool) {
+        for declarator in &decl.declarations {
+            let Some(name) = declarator.id.get_identifier_name() else {
+                continue;
+            };
+            let variable = Variable {
+                name: name.to_string(),
+                type_annotation: declarator
+                    .type_annotation
+                    .as_ref()
+                    .map(|t| self.text(t.type_annotation.span()).to_string()),
+                init: declarator.init.as_ref().map(|e| self.convert(e, 0)),
+                exported,
+                range: range(declarator.span),
+            };
+            self.module.variables.push(variable);
+        }
+    }
+
+    fn add_function(&mut self, func: &ast::Function<'_>, exported: bool) {
+        if let Some(id) = &func.id {
+            self.module.functions.push(Function {
+                name: id.name.to_string(),
+                return_type: func
+                    .return_type
+                    .as_ref()
+                    .map(|t| self.text(t.type_annotation.span()).to_string()),
+                exported,
+                range: range(func.span),
+            });
+        }
+    }
+
+    fn text(&self, span: Span) -> &str {
+        &self.source[span.start as usize..span.end as usize]
+    }
+
+    fn scope(&self) -> Scope {
+        let member = self.member_stack.last();
+        Scope {
+            class: self.class_stack.last().cloned(),
+            member: member.map(|(name, _)| name.clone()),
+            member_kind: member.map(|(_, kind)| *kind),
+            function: self.function_stack.last().cloned(),
+            in_closure: self.function_depth > self.scope_depth,
+        }
+    }
+
+    fn key_name(&self, key: &PropertyKey<'_>) -> String {
+        key.static_name()
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| self.text(key.span()).to_string())
+    }
+
+    fn convert_decorators(&self, decorators: &[ast::Decorator<'_>]) -> Vec<Decorator> {
+        decorators
+            .iter()
+            .map(|decorator| {
+                let (name, args) = match decorator.expression.get_inner_expression() {
+                    Expression::CallExpression(call) => (
+                        callee_text(&call.callee),
+                        call.arguments
+                            .iter()
+                            .map(|a| self.convert_argument(a, 0))
+                            .collect(),
+                    ),
+                    other => (callee_text(other), Vec::new()),
+                };
+                Decorator {
+                    name,
+                    args,
+                    range: range(decorator.span),
+                }
+            })
+            .collect()
+    }
+
+    fn convert_class(&self, class: &ast::Class<'_>) -> Class {
+        let mut members = Vec::new();
+        for element in &class.body.body {
+            let member = match element {
+                ClassElement::MethodDefinition(method) => {
+                    let kind = match method.kind {
+                        MethodDefinitionKind::Constructor => MemberKind::Constructor,
+                        MethodDefinitionKind::Method => MemberKind::Method,
+                        MethodDefinitionKind::Get => MemberKind::Getter,
+                        MethodDefinitionKind::Set => MemberKind::Setter,
+                    };
+                    let params = method
+                        .value
+                        .params
+                        .items
+                        .iter()
+                        .map(|param| Param {
+                            name: param
+                                .pattern
+                                .get_identifier_name()
+                                .map_or_else(String::new, |n| n.to_string()),
+                            type_annotation: param
+                                .type_annotation
+                                .as_ref()
+                                .map(|t| self.text(t.type_annotation.span()).to_string()),
+                            accessibility: param.accessibility.map(accessibility_name),
+                        })
+                        .collect();
+                    ClassMember {
+                        name: self.key_name(&method.key),
+                        kind,
+                        decorators: self.convert_decorators(&method.decorators),
+                        type_annotation: method
+                            .value
+                            .return_type
+                            .as_ref()
+                            .map(|t| self.text(t.type_annotation.span()).to_string()),
+                        value: None,
+                        params,
+                        accessibility: method.accessibility.map(accessibility_name),
+                        is_static: method.r#static,
+                        is_readonly: false,
+                        range: range(method.span),
+                    }
+                }
+                ClassElement::PropertyDefinition(prop) => ClassMember {
+                    name: self.key_name(&prop.key),
+                    kind: MemberKind::Property,
+                    decorators: self.convert_decorators(&prop.decorators),
+                    type_annotation: prop
+                        .type_annotation
+                        .as_ref()
+                        .map(|t| self.text(t.type_annotation.span()).to_string()),
+                    value: prop.value.as_ref().map(|v| self.convert(v, 0)),
+                    params: Vec::new(),
+                    accessibility: prop.accessibility.map(accessibility_name),
+                    is_static: prop.r#static,
+                    is_readonly: prop.readonly,
+                    range: range(prop.span),
+                },
+                ClassElement::AccessorProperty(prop) => ClassMember {
+                    name: self.key_name(&prop.key),
+                    kind: MemberKind::Property,
+                    decorators: self.convert_decorators(&prop.decorators),
+                    type_annotation: prop
+                        .type_annotation
+                        .as_ref()
+                        .map(|t| self.text(t.type_annotation.span()).to_string()),
+                    value: prop.value.as_ref().map(|v| self.convert(v, 0)),
+                    params: Vec::new(),
+                    accessibility: prop.accessibility.map(accessibility_name),
+                    is_static: prop.r#static,
+                    is_readonly: false,
+                    range: range(prop.span),
+                },
+                _ => continue,
+            };
+            members.push(member);
+        }
+
+        Class {
+            name: class
+                .id
+                .as_ref()
+                .map_or_else(String::new, |id| id.name.to_string()),
+            decorators: self.convert_decorators(&class.decorators),
+            extends: class.super_class.as_ref().map(callee_text),
+            implements: class
+                .implements
+                .iter()
+                .map(|i| i.expression.to_string())
+                .collect(),
+            members,
+            exported: false,
+            range: range(class.span),
+        }
+    }
+
+    fn convert_argument(&self, arg: &Argument<'_>, depth: usize) -> Expr {
+        match arg {
+            Argument::SpreadElement(spread) => Expr {
+                kind: ExprKind::Spread(Box::new(self.convert(&spread.argument, depth + 1))),
+                range: range(spread.span),
+            },
+            _ => self.convert(arg.to_expression(), depth),
+        }
+    }
+
+    fn convert(&self, expr: &Expression<'_>, depth: usize) -> Expr {
+        let expr = expr.get_inner_expression();
+        let kind = if depth > MAX_EXPR_DEPTH {
+            ExprKind::Other
+        } else {
+            self.convert_kind(expr, depth + 1)
+        };
+        Expr {
+            kind,
+            range: range(expr.span()),
+        }
+    }
+
+    fn convert_kind(&self, expr: &Expression<'_>, depth: usize) -> ExprKind {
+        match expr {
+            Expression::ObjectExpression(obj) => ExprKind::Object(
+                obj.properties
+                    .iter()
+                    .map(|prop| match prop {
+                        ObjectPropertyKind::ObjectProperty(p) => Property {
+                            key: self.key_name(&p.key),
+                            value: self.convert(&p.value, depth),
+                            range: range(p.span),
+                        },
+                        ObjectPropertyKind::SpreadProperty(spread) => Property {
+                            key: "...".to_string(),
+                            value: Expr {
+                                kind: ExprKind::Spread(Box::new(
+                                    self.convert(&spread.argument, depth),
+                                )),
+                                range: range(spread.span),
+                            },
+                            range: range(spread.span),
+                        },
+                    })
+                    .collect(),
+            ),
+            Expression::ArrayExpression(array) => ExprKind::Array(
+                array
+                    .elements
+                    .iter()
+                    .filter_map(|element| match element {
+                        ArrayExpressionElement::SpreadElement(spread) => Some(Expr {
+                            kind: ExprKind::Spread(Box::new(self.convert(&spread.argument, depth))),
+                            range: range(spread.span),
+                        }),
+                        ArrayExpressionElement::Elision(_) => None,
+                        _ => element.as_expression().map(|e| self.convert(e, depth)),
+                    })
+                    .collect(),
+            ),
+            Expression::StringLiteral(s) => ExprKind::Str(s.value.to_string()),
+            Expression::TemplateLiteral(t) => ExprKind::Template(self.template_text(t.span)),
+            Expression::NumericLiteral(n) => ExprKind::Num(n.value),
+            Expression::BooleanLiteral(b) => ExprKind::Bool(b.value),
+            Expression::NullLiteral(_) => ExprKind::Null,
+            Expression::Identifier(id) => ExprKind::Ident(id.name.to_string()),
+            Expression::ThisExpression(_) => ExprKind::This,
+            Expression::StaticMemberExpression(member) => ExprKind::Member {
+                object: Box::new(self.convert(&member.object, depth)),
+                property: member.property.name.to_string(),
+            },
+            Expression::ComputedMemberExpression(member) => match &member.expression {
+                Expression::StringLiteral(s) => ExprKind::Member {
+                    object: Box::new(self.convert(&member.object, depth)),
+                    property: s.value.to_string(),
+                },
+                _ => ExprKind::Other,
+            },
+            Expression::CallExpression(call) => ExprKind::Call {
+                callee: Box::new(self.convert(&call.callee, depth)),
+                args: call
+                    .arguments
+                    .iter()
+                    .map(|a| self.convert_argument(a, depth))
+                    .collect(),
+            },
+            Expression::NewExpression(new) => ExprKind::New {
+                callee: Box::new(self.convert(&new.callee, depth)),
+                args: new
+                    .arguments
+                    .iter()
+                    .map(|a| self.convert_argument(a, depth))
+                    .collect(),
+            },
+            Expression::ImportExpression(import) => match &import.source {
+                Expression::StringLiteral(s) => ExprKind::Import(s.value.to_string()),
+                _ => ExprKind::Other,
+            },
+            Expression::ArrowFunctionExpression(arrow) => ExprKind::Function {
+                body: single_expression(&arrow.body.statements)
+                    .map(|e| Box::new(self.convert(e, depth))),
+            },
+            Expression::FunctionExpression(func) => ExprKind::Function {
+                body: func
+                    .body
+                    .as_ref()
+                    .and_then(|body| single_expression(&body.statements))
+                    .map(|e| Box::new(self.convert(e, depth))),
+            },
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => ExprKind::Call {
+                    callee: Box::new(self.convert(&call.callee, depth)),
+                    args: call
+                        .arguments
+                        .iter()
+                        .map(|a| self.convert_argument(a, depth))
+                        .collect(),
+                },
+                ChainElement::StaticMemberExpression(member) => ExprKind::Member {
+                    object: Box::new(self.convert(&member.object, depth)),
+                    property: member.property.name.to_string(),
+                },
+                _ => ExprKind::Other,
+            },
+            _ => ExprKind::Other,
+        }
+    }
+
+    // バッククォートを除いたテンプレートリテラルの中身
+    fn template_text(&self, span: Span) -> String {
+        let text = self.text(span);
+        text.get(1..text.len().saturating_sub(1))
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    // 右辺が (括弧や as を除いて) 呼び出しなら、その呼び出しの代入先として覚えておく
+    fn assign(&mut self, value: &Expression<'_>, target: String) {
+        if let Expression::CallExpression(call) = value.get_inner_expression() {
+            self.assignments
+                .insert((call.span.start, call.span.end), target);
+        }
+    }
+
+    fn with_member(&mut self, name: String, kind: MemberKind, f: impl FnOnce(&mut Self)) {
+        self.member_stack.push((name, kind));
+        // フィールドの初期化子は関数の外、メソッドは自身の関数が本体
+        let body = self.function_depth + usize::from(kind != MemberKind::Property);
+        self.with_body(body, f);
+        self.member_stack.pop();
+    }
+
+    fn with_body(&mut self, depth: usize, f: impl FnOnce(&mut Self)) {
+        let outer = std::mem::replace(&mut self.scope_depth, depth);
+        f(self);
+        self.scope_depth = outer;
+    }
+}
+
+impl<'a> Visit<'a> for Collector<'_> {
+    fn visit_import_declaration(&mut self, it: &ast::ImportDeclaration<'a>) {
+        let names = it
+            .specifiers
+            .iter()
+            .flatten()
+            .map(|spec| match spec {
+                ImportDeclarationSpecifier::ImportSpecifier(s) => s.imported.name().to_string(),
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => "default".to_string(),
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => "*".to_string(),
+            })
+            .collect();
+        self.module.imports.push(Import {
+            source: it.source.value.to_string(),
+            names,
+            kind: ImportKind::Static,
+            type_only: it.import_kind.is_type(),
+            range: range(it.span),
+        });
+        if let Some(specifiers) = &it.specifiers {
+            self.visit_import_declaration_specifiers(specifiers);
+        }
+    }
+
+    fn visit_export_named_declaration(&mut self, it: &ast::ExportNamedDeclaration<'a>) {
+        if let Some(source) = &it.source {
+            self.module.imports.push(Import {
+                source: source.value.to_string(),
+                names: it
+                    .specifiers
+                    .iter()
+                    .map(|s| s.local.name().to_string())
+                    .collect(),
+                kind: ImportKind::ReExport,
+                type_only: it.export_kind.is_type(),
+                range: range(it.span),
+            });
+            return;
+        }
+        walk::walk_export_named_declaration(self, it);
+    }
+
+    fn visit_export_all_declaration(&mut self, it: &ast::ExportAllDeclaration<'a>) {
+        self.module.imports.push(Import {
+            source: it.source.value.to_string(),
+            names: vec!["*".to_string()],
+            kind: ImportKind::ReExport,
+            type_only: it.export_kind.is_type(),
+            range: range(it.span),
+        });
+    }
+
+    fn visit_import_expression(&mut self, it: &ast::ImportExpression<'a>) {
+        if let Expression::StringLiteral(s) = &it.source {
+            self.module.imports.push(Import {
+                source: s.value.to_string(),
+                names: Vec::new(),
+                kind: ImportKind::Dynamic,
+                type_only: false,
+                range: range(it.span),
+            });
+        }
+        walk::walk_import_expression(self, it);
+    }
+
+    fn visit_class(&mut self, it: &ast::Class<'a>) {
+        let class = self.convert_class(it);
+        self.class_stack.push(class.name.clone());
+        self.module.classes.push(class);
+
+        let members = std::mem::take(&mut self.member_stack);
+        walk::walk_class(self, it);
+        self.member_stack = members;
+        self.class_stack.pop();
+    }
+
+    fn visit_method_definition(&mut self, it: &ast::MethodDefinition<'a>) {
+        let kind = match it.kind {
+            MethodDefinitionKind::Constructor => MemberKind::Constructor,
+            MethodDefinitionKind::Method => MemberKind::Method,
+            MethodDefinitionKind::Get => MemberKind::Getter,
+            MethodDefinitionKind::Set => MemberKind::Setter,
+        };
+        let name = self.key_name(&it.key);
+        self.with_member(name, kind, |v| walk::walk_method_definition(v, it));
+    }
+
+    fn visit_property_definition(&mut self, it: &ast::PropertyDefinition<'a>) {
+        let name = self.key_name(&it.key);
+        if let Some(value) = &it.value {
+            self.assign(value, format!("this.{}", name));
+        }
+        self.with_member(name, MemberKind::Property, |v| {
+            walk::walk_property_definition(v, it)
+        });
+    }
+
+    fn visit_function(&mut self, it: &ast::Function<'a>, flags: ScopeFlags) {
+        let top_level = self.class_stack.is_empty() && self.function_stack.is_empty();
+        self.function_depth += 1;
+        match (&it.id, top_level) {
+            (Some(id), true) => {
+                self.function_stack.push(id.name.to_string());
+                self.with_body(self.function_depth, |v| walk::walk_function(v, it, flags));
+                self.function_stack.pop();
+            }
+            _ => walk::walk_function(self, it, flags),
+        }
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ast::ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, it);
+        self.function_depth -= 1;
+    }
+
+    // const authGuard: CanActivateFn = () => ... のような関数定義
+    fn visit_variable_declarator(&mut self, it: &ast::VariableDeclarator<'a>) {
+        if let (Some(init), Some(name)) = (&it.init, it.id.get_identifier_name()) {
+            self.assign(init, name.to_string());
+        }
+        let top_level = self.class_stack.is_empty() && self.function_stack.is_empty();
+        let is_function = it.init.as_ref().is_some_and(|init| {
+            matches!(
+                init.get_inner_expression(),
+                Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+            )
+        });
+        match it.id.get_identifier_name() {
+            Some(name) if top_level && is_function => {
+                self.function_stack.push(name.to_string());
+                // 初期化子の関数式そのものが本体
+                self.with_body(self.function_depth + 1, |v| {
+                    walk::walk_variable_declarator(v, it)
+                });
+                self.function_stack.pop();
+            }
+            _ => walk::walk_variable_declarator(self, it),
+        }
+    }
+
+    fn visit_assignment_expression(&mut self, it: &ast::AssignmentExpression<'a>) {
+        if it.operator == AssignmentOperator::Assign {
+            let target = self.text(it.left.span()).to_string();
+            self.assign(&it.right, target);
+        }
+        walk::walk_assignment_expression(self, it);
+    }
+
+    fn visit_call_expression(&mut self, it: &ast::CallExpression<'a>) {
+        let index = self.module.calls.len();
+        self.module.calls.push(Call {
+            callee: callee_text(&it.callee),
+            args: it
+                .arguments
+                .iter()
+                .map(|a| self.convert_argument(a, 0))
+                .collect(),
+            scope: self.scope(),
+            enclosing_calls: self.call_stack.clone(),
+            assigned_to: self.assignments.remove(&(it.span.start, it.span.end)),
+            range: range(it.span),
+        });
+
+        // 呼び出し先の式は外側の文脈、引数はこの呼び出しの内側として扱う
+        self.visit_expression(&it.callee);
+        self.call_stack.push(index);
+        self.visit_arguments(&it.arguments);
+        self.call_stack.pop();
+    }
+
+    fn visit_static_member_expression(&mut self, it: &ast::StaticMemberExpression<'a>) {
+        self.module.members.push(MemberAccess {
+            object: callee_text(&it.object),
+            property: it.property.name.to_string(),
+            scope: self.scope(),
+            range: range(it.property.span),
+        });
+        walk::walk_static_member_expression(self, it);
+    }
+
+    fn visit_identifier_reference(&mut self, it: &ast::IdentifierReference<'a>) {
+        self.module.names.insert(it.name.to_string());
+    }
+
+    fn visit_identifier_name(&mut self, it: &ast::IdentifierName<'a>) {
+        self.module.names.insert(it.name.to_string());
+    }
+
+    fn visit_binding_identifier(&mut self, it: &ast::BindingIdentifier<'a>) {
+        self.module.names.insert(it.name.to_string());
+    }
+
+    fn visit_string_literal(&mut self, it: &ast::StringLiteral<'a>) {
+        self.module.strings.push(StringLiteral {
+            value: it.value.to_string(),
+            range: range(it.span),
+        });
+    }
+
+    fn visit_template_literal(&mut self, it: &ast::TemplateLiteral<'a>) {
+        self.module.strings.push(StringLiteral {
+            value: self.template_text(it.span),
+            range: range(it.span),
+        });
+        walk::walk_template_literal(self, it);
+    }
+
+    fn visit_try_statement(&mut self, it: &ast::TryStatement<'a>) {
+        self.module.try_blocks.push(range(it.span));
+        walk::walk_try_statement(self, it);
+    }
+}
+
+fn range(span: Span) -> TextRange {
+    TextRange {
+        start: span.start as usize,
+        end: span.end as usize,
+    }
+}
+
+fn accessibility_name(accessibility: TSAccessibility) -> String {
+    match accessibility {
+        TSAccessibility::Private => "private",
+        TSAccessibility::Protected => "protected",
+        TSAccessibility::Public => "public",
+    }
+    .to_string()
+}
+
+// 本体が式1つ、または return 文1つだけの関数の戻り値の式
+fn single_expression<'b, 'a>(statements: &'b [Statement<'a>]) -> Option<&'b Expression<'a>> {
+    match statements {
+        [Statement::ExpressionStatement(stmt)] => Some(&stmt.expression),
+        [Statement::ReturnStatement(ret)] => ret.argument.as_ref(),
+        _ => None,
+    }
+}
+
+// 呼び出し先などの表記 (例: "this.http.get", "interval().subscribe")
+fn callee_text(expr: &Expression<'_>) -> String {
+    match expr.get_inner_expression() {
+        Expression::Identifier(id) => id.name.to_string(),
+        Expression::ThisExpression(_) => "this".to_string(),
+        Expression::Super(_) => "super".to_string(),
+        Expression::StaticMemberExpression(member) => {
+            format!("{}.{}", callee_text(&member.object), member.property.name)
+        }
+        Expression::ComputedMemberExpression(member) => match &member.expression {
+            Expression::StringLiteral(s) => format!("{}.{}", callee_text(&member.object), s.value),
+            _ => format!("{}[]", callee_text(&member.object)),
+        },
+        Expression::PrivateFieldExpression(member) => {
+            format!("{}.#{}", callee_text(&member.object), member.field.name)
+        }
+        Expression::CallExpression(call) => format!("{}()", callee_text(&call.callee)),
+        Expression::NewExpression(new) => format!("new {}()", callee_text(&new.callee)),
+        Expression::ImportExpression(_) => "import()".to_string(),
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::CallExpression(call) => format!("{}()", callee_text(&call.callee)),
+            ChainElement::StaticMemberExpression(member) => {
+                format!("{}.{}", callee_text(&member.object), member.property.name)
+            }
+            _ => "?".to_string(),
+        },
+        _ => "?".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Module {
+        parse_module("test.ts", source).expect("parse")
+    }
+
+    #[test]
+    fn imports() {
+        let module = parse(
+            "import { A, B as C } from './a';\n\
+             import type { T } from './t';\n\
+             import * as ns from 'ns';\n\
+             import D from 'd';\n\
+             export { E } from './e';\n\
+             const lazy = () => import('./lazy');",
+        );
+        let imports: Vec<_> = module
+            .imports
+            .iter()
+            .map(|i| (i.source.as_str(), i.names.join(","), i.kind, i.type_only))
+            .collect();
+        assert_eq!(
+            imports,
+            [
+                ("./a", "A,B".to_string(), ImportKind::Static, false),
+                ("./t", "T".to_string(), ImportKind::Static, true),
+                ("ns", "*".to_string(), ImportKind::Static, false),
+                ("d", "default".to_string(), ImportKind::Static, false),
+                ("./e", "E".to_string(), ImportKind::ReExport, false),
+                ("./lazy", String::new(), ImportKind::Dynamic, false),
+            ]
+        );
+        // 別名ではなく import 元での名前を持つ
+        assert!(module.imports_name("./a", "B"));
+    }
+
+    #[test]
+    fn classes_and_members() {
+        let source = "@Component({ selector: 'app-x', standalone: true, imports: [A, B] })\n\
+             export class X extends Base implements OnInit, OnDestroy {\n\
+               @Input() name: string;\n\
+               private readonly items = signal<number[]>([]);\n\
+               static count = 0;\n\
+               constructor(private http: HttpClient, store: Store) { super(); }\n\
+               ngOnInit(): void {}\n\
+               get total() { return 1; }\n\
+             }\n\
+             class Hidden {}";
+        let module = parse(source);
+        assert_eq!(module.classes.len(), 2);
+        let class = &module.classes[0];
+        assert!(class.exported);
+        assert!(!module.classes[1].exported);
+        assert_eq!(class.extends.as_deref(), Some("Base"));
+        assert!(class.implements("OnDestroy"));
+
+        let metadata = class.decorator("Component").unwrap().metadata().unwrap();
+        let keys: Vec<_> = metadata.iter().map(|p| p.key.as_str()).collect();
+        assert_eq!(keys, ["selector", "standalone", "imports"]);
+        assert_eq!(metadata[0].value.as_str(), Some("app-x"));
+        assert_eq!(metadata[1].value.as_bool(), Some(true));
+        assert_eq!(metadata[2].value.items().len(), 2);
+
+        let members: Vec<_> = class
+            .members
+            .iter()
+            .map(|m| (m.name.as_str(), m.kind))
+            .collect();
+        assert_eq!(
+            members,
+            [
+                ("name", MemberKind::Property),
+                ("items", MemberKind::Property),
+                ("count", MemberKind::Property),
+                ("constructor", MemberKind::Constructor),
+                ("ngOnInit", MemberKind::Method),
+                ("total", MemberKind::Getter),
+            ]
+        );
+        let name = class.member("name").unwrap();
+        assert!(name.decorator("Input").is_some());
+        assert_eq!(name.type_annotation.as_deref(), Some("string"));
+        let items = class.member("items").unwrap();
+        assert!(!items.is_public() && items.is_readonly);
+        assert!(class.member("count").unwrap().is_static);
+
+        let params = &class.member("constructor").unwrap().params;
+        assert_eq!(params[0].name, "http");
+        assert_eq!(params[0].type_annotation.as_deref(), Some("HttpClient"));
+        assert_eq!(params[0].accessibility.as_deref(), Some("private"));
+        assert_eq!(params[1].accessibility, None);
+
+        let range = class.decorator("Component").unwrap().range;
+        assert!(source[range.start..range.end].starts_with("@Component"));
+    }
+
+    #[test]
+    fn calls_scope_and_nesting() {
+        let module = parse(
+            "class C {\n\
+               ngOnInit() {\n\
+                 this.http.get(url).pipe(map(x => x)).subscribe(v => this.save(v));\n\
+               }\n\
+             }\n\
+             function helper() { inject(Store); }\n\
+             bootstrap();",
+        );
+        let subscribe = module.method_calls("subscribe").next().unwrap();
+        assert_eq!(subscribe.callee, "this.http.get().pipe().subscribe");
+        assert_eq!(subscribe.receiver(), Some("this.http.get().pipe()"));
+        assert_eq!(subscribe.scope.class.as_deref(), Some("C"));
+        assert_eq!(subscribe.scope.member.as_deref(), Some("ngOnInit"));
+        assert_eq!(subscribe.scope.member_kind, Some(MemberKind::Method));
+
+        let save = module.calls_to("this.save").next().unwrap();
+        assert!(
+            save.enclosing_calls
+                .iter()
+                .any(|&i| module.calls[i].method() == "subscribe")
+        );
+        let map = module.calls_to("map").next().unwrap();
+        assert!(
+            map.enclosing_calls
+                .iter()
+                .all(|&i| module.calls[i].method() != "subscribe")
+        );
+
+        let inject = module.calls_to("inject").next().unwrap();
+        assert_eq!(inject.scope.function.as_deref(), Some("helper"));
+        assert_eq!(inject.args[0].path().as_deref(), Some("Store"));
+        let bootstrap = module.calls_to("bootstrap").next().unwrap();
+        assert!(bootstrap.scope.class.is_none() && bootstrap.scope.function.is_none());
+    }
+
+    #[test]
+    fn calls_in_closures() {
+        let module = parse(
+            "class C {\n\
+               a = effect(() => log());\n\
+               b = () => effect(() => {});\n\
+               constructor() { effect(() => {}); setTimeout(function () { effect(() => {}); }); }\n\
+             }\n\
+             const guard = () => inject(Auth);\n\
+             function helper() { const f = () => inject(Store); }",
+        );
+        let closures = |callee: &str| -> Vec<bool> {
+            module
+                .calls_to(callee)
+                .map(|c| c.scope.in_closure)
+                .collect()
+        };
+        // 初期化子・コンストラクター・トップレベル関数の本体は関数式の中ではない
+        assert_eq!(closures("effect"), [false, true, false, true]);
+        assert_eq!(closures("log"), [true]);
+        assert_eq!(closures("inject"), [false, true]);
+    }
+
+    #[test]
+    fn call_assignment_targets() {
+        let module = parse(
+            "class C {\n\
+               sub = this.a$.subscribe();\n\
+               ngOnInit() {\n\
+                 this.other =\n\
+                   this.b$\n\
+                     .pipe(map(x => x))\n\
+                     .subscribe();\n\
+                 const local = (this.c$.subscribe() as Subscription);\n\
+                 this.list.push(this.d$.subscribe());\n\
+                 this.count += this.e$.subscribe().closed ? 1 : 0;\n\
+               }\n\
+             }",
+        );
+        let targets: Vec<Option<&str>> = module
+            .method_calls("subscribe")
+            .map(|c| c.assigned_to.as_deref())
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                Some("this.sub"),
+                Some("this.other"),
+                Some("local"),
+                None,
+                None
+            ]
+        );
+        // 右辺の途中の呼び出し (pipe) には代入先を付けない
+        assert_eq!(
+            module.method_calls("pipe").next().unwrap().assigned_to,
+            None
+        );
+    }
+
+    #[test]
+    fn variables_functions_and_expressions() {
+        let module = parse(
+            "export const routes: Routes = [\n\
+               { path: 'a', loadChildren: () => import('./a').then(m => m.A) },\n\
+               ...extra,\n\
+               `tpl`,\n\
+             ];\n\
+             const local = new Subject<number>();\n\
+             export function guard(role: string): CanActivateFn { return () => true; }",
+        );
+        let routes = module.variable("routes").unwrap();
+        assert!(routes.exported);
+        assert_eq!(routes.type_annotation.as_deref(), Some("Routes"));
+        let items = routes.init.as_ref().unwrap().items();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].get("path").and_then(Expr::as_str), Some("a"));
+        assert!(
+            matches!(&items[1].kind, ExprKind::Spread(inner) if inner.path().as_deref() == Some("extra"))
+        );
+        assert_eq!(items[2].as_str(), Some("tpl"));
+
+        let mut imported = None;
+        items[0].get("loadChildren").unwrap().walk(&mut |e| {
+            if let ExprKind::Import(source) = &e.kind {
+                imported = Some(source.clone());
+            }
+        });
+        assert_eq!(imported.as_deref(), Some("./a"));
+
+        let local = module.variable("local").unwrap();
+        assert!(!local.exported);
+        assert!(
+            matches!(&local.init.as_ref().unwrap().kind, ExprKind::New { callee, .. } if callee.path().as_deref() == Some("Subject"))
+        );
+
+        assert_eq!(module.functions[0].name, "guard");
+        assert!(module.functions[0].exported);
+        assert_eq!(
+            module.functions[0].return_type.as_deref(),
+            Some("CanActivateFn")
+        );
+    }
+
+    #[test]
+    fn names_skip_comments_and_strings() {
+        let module = parse(
+            "// localStorage in a comment\n\
+             const key = 'sessionStorage';\n\
+             window.indexedDB.open(key);",
+        );
+        assert!(module.has_name("indexedDB"));
+        assert!(!module.has_name("localStorage"));
+        assert!(!module.has_name("sessionStorage"));
+        assert_eq!(module.strings[0].value, "sessionStorage");
+        assert_eq!(module.member_accesses("indexedDB").count(), 1);
+    }
+
+    #[test]
+    fn deep_nesting_and_syntax_errors() {
+        let nested = format!("const x = {}1{};", "[".repeat(500), "]".repeat(500));
+        assert!(parse_module("deep.ts", &nested).is_some());
+        assert!(parse_module("broken.ts", "class { ").is_none());
+    }
+}
@@ -0,0 +1,278 @@
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+use crate::index::resolve_path;
+use crate::jsonc;
+
+// extends をたどる深さの上限 (循環した extends で止まらないように)
+const MAX_EXTENDS: usize = 8;
+
+// tsconfig.json のうち import の解決に使う設定 (extends を反映済み)
+#[derive(Debug, Clone, Default)]
+pub struct TsConfig {
+    pub path: String,
+    // extends でたどったファイル (近い順)
+    pub extends: Vec<String>,
+    // 絶対パスに解決済みの baseUrl
+    pub base_url: Option<String>,
+    pub paths: Vec<PathAlias>,
+    // paths を定義したファイル。baseUrl が無い場合はこのディレクトリが paths の基準になる
+    pub paths_source: String,
+}
+
+// compilerOptions.paths の 1 エントリ ("@app/*": ["src/app/*"] など)
+#[derive(Debug, Clone)]
+pub struct PathAlias {
+    pub pattern: String,
+    pub targets: Vec<String>,
+}
+
+impl TsConfig {
+    // 読み込めない、または JSON として解釈できないファイルは None
+    pub fn load(path: &str) -> Option<Self> {
+        Self::load_chain(path, 0)
+    }
+
+    fn load_chain(path: &str, depth: usize) -> Option<Self> {
+        let value = jsonc::parse(&fs::read_to_string(path).ok()?)?;
+        let mut config = TsConfig {
+            path: path.to_string(),
+            paths_source: path.to_string(),
+            ..Default::default()
+        };
+
+        // 親の設定を先に反映し、このファイルの compilerOptions で上書きする
+        let extends: Vec<&str> = match value.get("extends") {
+            Some(Value::String(name)) => vec![name],
+            Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        for name in extends {
+            let Some(parent) = resolve_extends(path, name)
+                .filter(|_| depth < MAX_EXTENDS)
+                .and_then(|p| Self::load_chain(&p, depth + 1))
+            else {
+                continue;
+            };
+            config.extends.push(parent.path);
+            config.extends.extend(parent.extends);
+            if parent.base_url.is_some() {
+                config.base_url = parent.base_url;
+            }
+            if !parent.paths.is_empty() {
+                config.paths = parent.paths;
+                config.paths_source = parent.paths_source;
+            }
+        }
+
+        let options = value.get("compilerOptions");
+        if let Some(base_url) = options
+            .and_then(|o| o.get("baseUrl"))
+            .and_then(Value::as_str)
+        {
+            config.base_url = Some(resolve_path(path, base_url));
+        }
+        if let Some(paths) = options
+            .and_then(|o| o.get("paths"))
+            .and_then(Value::as_object)
+        {
+            config.paths = paths
+                .iter()
+                .map(|(pattern, targets)| PathAlias {
+                    pattern: pattern.clone(),
+                    targets: targets
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(Value::as_str)
+                        .map(String::from)
+                        .collect(),
+                })
+                .collect();
+            config.paths_source = path.to_string();
+        }
+        Some(config)
+    }
+
+    // specifier に一致する paths エイリアスの置換先 (試す順)。一致しなければ None
+    // TypeScript と同じく完全一致を優先し、次に * より前が最も長いパターンを使う
+    pub fn alias_targets(&self, specifier: &str) -> Option<Vec<String>> {
+        let mut best: Option<(&PathAlias, &str, usize)> = None;
+        for alias in &self.paths {
+            match alias.pattern.split_once('*') {
+                None if alias.pattern == specifier => {
+                    best = Some((alias, "", usize::MAX));
+                    break;
+                }
+                None => {}
+                Some((prefix, suffix)) => {
+                    let matches = specifier.len() >= prefix.len() + suffix.len()
+                        && specifier.starts_with(prefix)
+                        && specifier.ends_with(suffix);
+                    if matches && best.is_none_or(|(_, _, len)| prefix.len() > len) {
+                        let captured = &specifier[prefix.len()..specifier.len() - suffix.len()];
+                        best = Some((alias, captured, prefix.len()));
+                    }
+                }
+            }
+        }
+
+        let (alias, captured, _) = best?;
+        let base = self.base_url.as_deref().unwrap_or_else(|| {
+            self.paths_source
+                .rsplit_once('/')
+                .map_or("", |(dir, _)| dir)
+        });
+        Some(
+            alias
+                .targets
+                .iter()
+                .map(|target| join(base, &target.replacen('*', captured, 1)))
+                .collect(),
+        )
+    }
+}
+
+// ディレクトリからの相対パスを解決する
+pub fn join(dir: &str, relative: &str) -> String {
+    resolve_path(&format!("{}/", dir), relative)
+}
+
+// extends の指定をファイルパスにする。相対パスでなければ node_modules のパッケージを探す
+fn resolve_extends(config_path: &str, name: &str) -> Option<String> {
+    let bases = if name.starts_with('.') || name.starts_with('/') {
+        vec![resolve_path(config_path, name)]
+    } else {
+        Path::new(config_path)
+            .ancestors()
+            .skip(1)
+            .filter_map(|dir| dir.to_str())
+            .filter(|dir| !dir.is_empty())
+            .map(|dir| join(dir, &format!("node_modules/{}", name)))
+            .collect()
+    };
+    bases
+        .iter()
+        .flat_map(|base| {
+            [
+                base.clone(),
+                format!("{}.json", base),
+                format!("{}/tsconfig.json", base),
+            ]
+        })
+        .find(|candidate| Path::new(candidate).is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    fn alias(pattern: &str, targets: &[&str]) -> PathAlias {
+        PathAlias {
+            pattern: pattern.to_string(),
+            targets: targets.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn alias_targets_prefers_exact_then_longest_prefix() {
+        let config = TsConfig {
+            path: "/ws/tsconfig.json".to_string(),
+            base_url: Some("/ws".to_string()),
+            paths: vec![
+                alias("@app/*", &["src/app/*"]),
+                alias("@app/core/*", &["libs/core/src/*", "fallback/*"]),
+                alias("@env", &["src/environments/environment.ts"]),
+                alias("*", &["vendor/*"]),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            config.alias_targets("@app/core/auth"),
+            Some(vec![
+                "/ws/libs/core/src/auth".to_string(),
+                "/ws/fallback/auth".to_string()
+            ])
+        );
+        assert_eq!(
+            config.alias_targets("@app/shared/x"),
+            Some(vec!["/ws/src/app/shared/x".to_string()])
+        );
+        assert_eq!(
+            config.alias_targets("@env"),
+            Some(vec!["/ws/src/environments/environment.ts".to_string()])
+        );
+        assert_eq!(
+            config.alias_targets("rxjs"),
+            Some(vec!["/ws/vendor/rxjs".to_string()])
+        );
+    }
+
+    #[test]
+    fn alias_targets_without_base_url_use_paths_source_dir() {
+        let config = TsConfig {
+            path: "/ws/apps/a/tsconfig.json".to_string(),
+            paths: vec![alias("@lib/*", &["libs/*"])],
+            paths_source: "/ws/tsconfig.base.json".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.alias_targets("@lib/ui"),
+            Some(vec!["/ws/libs/ui".to_string()])
+        );
+        assert_eq!(config.alias_targets("other"), None);
+    }
+
+    #[test]
+    fn extends_chain_with_relative_and_package_bases() {
+        let project = TempProject::new(&[
+            (
+                "node_modules/@tsconfig/strictest/tsconfig.json",
+                r#"{ "compilerOptions": { "baseUrl": "." } }"#,
+            ),
+            (
+                "tsconfig.base.json",
+                r#"{
+                    // コメントと末尾カンマを許す
+                    "extends": "@tsconfig/strictest/tsconfig.json",
+                    "compilerOptions": { "paths": { "@app/*": ["src/app/*"], }, },
+                }"#,
+            ),
+            (
+                "apps/a/tsconfig.json",
+                r#"{ "extends": ["../../tsconfig.base"], "compilerOptions": { "baseUrl": "src" } }"#,
+            ),
+        ]);
+        let config = TsConfig::load(&project.path("apps/a/tsconfig.json")).unwrap();
+        assert_eq!(
+            config.extends,
+            [
+                project.path("tsconfig.base.json"),
+                project.path("node_modules/@tsconfig/strictest/tsconfig.json"),
+            ]
+        );
+        // このファイルの baseUrl が親の baseUrl を上書きし、paths は親のものを引き継ぐ
+        assert_eq!(config.base_url, Some(project.path("apps/a/src")));
+        assert_eq!(config.paths_source, project.path("tsconfig.base.json"));
+        assert_eq!(
+            config.alias_targets("@app/x"),
+            Some(vec![project.path("apps/a/src/src/app/x")])
+        );
+    }
+
+    #[test]
+    fn circular_extends_stops() {
+        let project = TempProject::new(&[
+            ("a.json", r#"{ "extends": "./b.json" }"#),
+            (
+                "b.json",
+                r#"{ "extends": "./a.json", "compilerOptions": { "baseUrl": "." } }"#,
+            ),
+        ]);
+        let config = TsConfig::load(&project.path("a.json")).unwrap();
+        assert_eq!(config.base_url, Some(project.root.clone()));
+        assert!(config.extends.len() <= MAX_EXTENDS + 1);
+    }
+}
@@ -0,0 +1,403 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::DepConstraint;
+use crate::index::{self, FileKind, ProjectIndex};
+use crate::jsonc;
+use crate::tsconfig;
+
+// ESLint で依存制約を設定するルール名 (旧パッケージ名を含む)
+const BOUNDARY_RULES: &[&str] = &[
+    "@nx/enforce-module-boundaries",
+    "@nrwl/nx/enforce-module-boundaries",
+];
+
+// ワークスペースの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WorkspaceKind {
+    Nx,
+    AngularCli,
+}
+
+// モノレポ内の 1 プロジェクト (アプリケーションまたはライブラリ)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub name: String,
+    // プロジェクトのディレクトリ (インデックスと同じ形式のパス)
+    pub root: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_root: Option<String>,
+    // "application" / "library" (指定が無ければ空)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub project_type: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build: Option<BuildOptions>,
+    // 定義元のファイル (project.json / angular.json)
+    pub file: String,
+}
+
+impl Project {
+    // プロジェクト単位で解析するディレクトリ (sourceRoot があればそちら)
+    // angular.json の root が "" のアプリケーションでも projects/ 以下のライブラリを含めないため
+    pub fn analysis_root(&self) -> &str {
+        self.source_root.as_deref().unwrap_or(&self.root)
+    }
+}
+
+// build ターゲットの本番向け設定 (options に既定の configuration を重ねた値)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuildOptions {
+    pub builder: String,
+    // 重ねた configuration の名前 (通常は "production")
+    pub configuration: String,
+    pub budgets: Vec<Budget>,
+    // オブジェクト形式 ({ "scripts": true, ... }) はいずれかが有効なら true
+    pub optimization: Option<bool>,
+    pub output_hashing: Option<String>,
+    // ngsw-config.json のパスを指定する形式も true
+    pub service_worker: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Budget {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_warning: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    pub kind: WorkspaceKind,
+    // 定義元のファイル (nx.json / angular.json)
+    pub file: String,
+    pub projects: Vec<Project>,
+    // ルートの .eslintrc.json に書かれた依存制約
+    pub dep_constraints: Vec<DepConstraint>,
+}
+
+impl Workspace {
+    pub fn project(&self, name: &str) -> Option<&Project> {
+        self.projects.iter().find(|p| p.name == name)
+    }
+
+    // path を含むプロジェクト。入れ子の場合はルートが最も深いもの
+    pub fn project_of(&self, path: &str) -> Option<usize> {
+        self.projects
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| index::is_under(path, &p.root))
+            .max_by_key(|(_, p)| p.root.len())
+            .map(|(i, _)| i)
+    }
+
+    // dir 以下にあるプロジェクト。無ければ dir を含むプロジェクト (sourceRoot を解析する場合)
+    // ワークスペースのルートなら全プロジェクト、--projects の各プロジェクトならそのプロジェクト
+    pub fn projects_in(&self, dir: &str) -> Vec<&Project> {
+        let dir = dir.trim_end_matches('/');
+        let inside: Vec<&Project> = self
+            .projects
+            .iter()
+            .filter(|p| p.root == dir || index::is_under(&p.root, dir))
+            .collect();
+        if !inside.is_empty() {
+            return inside;
+        }
+        self.project_of(dir)
+            .map(|i| &self.projects[i])
+            .into_iter()
+            .collect()
+    }
+}
+
+// ルートの nx.json または angular.json からワークスペースを読み込む
+// Nx では各 project.json を、angular.json があればその projects も (同名を除いて) 集める
+pub fn discover(index: &ProjectIndex) -> Option<Workspace> {
+    let root = index.root.trim_end_matches('/');
+    let nx_json = format!("{}/nx.json", root);
+    let angular_json = format!("{}/angular.json", root);
+
+    let mut projects: Vec<Project> = index
+        .files(FileKind::Json)
+        .filter(|f| f.path.ends_with("/project.json"))
+        .filter_map(|f| {
+            let value = jsonc::parse(f.content()?)?;
+            let dir = f.path.rsplit_once('/').map_or("", |(dir, _)| dir);
+            // name が無い project.json はディレクトリ名で呼ぶ (Nx の既定と同じ)
+            let name = value
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_else(|| dir.rsplit('/').next().unwrap_or(dir));
+            Some(parse_project(name, dir, &value, root, &f.path))
+        })
+        .collect();
+
+    let angular = index
+        .get(&angular_json)
+        .and_then(|f| f.content())
+        .and_then(jsonc::parse);
+    if let Some(definitions) = angular
+        .as_ref()
+        .and_then(|v| v.get("projects"))
+        .and_then(Value::as_object)
+    {
+        for (name, definition) in definitions {
+            if projects.iter().any(|p| &p.name == name) {
+                continue;
+            }
+            let dir = tsconfig::join(
+                root,
+                definition.get("root").and_then(Value::as_str).unwrap_or(""),
+            );
+            projects.push(parse_project(name, &dir, definition, root, &angular_json));
+        }
+    }
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let (kind, file) = if index.get(&nx_json).is_some() {
+        (WorkspaceKind::Nx, nx_json)
+    } else if angular.is_some() {
+        (WorkspaceKind::AngularCli, angular_json)
+    } else {
+        return None;
+    };
+    Some(Workspace {
+        kind,
+        file,
+        projects,
+        dep_constraints: eslint_dep_constraints(index),
+    })
+}
+
+// project.json と angular.json の projects.<name> は同じ形式 (targets は architect とも書ける)
+fn parse_project(name: &str, dir: &str, value: &Value, root: &str, file: &str) -> Project {
+    let targets = value.get("targets").or_else(|| value.get("architect"));
+    Project {
+        name: name.to_string(),
+        root: dir.to_string(),
+        source_root: value
+            .get("sourceRoot")
+            .and_then(Value::as_str)
+            .map(|s| tsconfig::join(root, s)),
+        project_type: value
+            .get("projectType")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        tags: value
+            .get("tags")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect(),
+        build: targets.and_then(|t| t.get("build")).map(build_options),
+        file: file.to_string(),
+    }
+}
+
+fn build_options(target: &Value) -> BuildOptions {
+    let options = target.get("options");
+    let configuration = target
+        .get("defaultConfiguration")
+        .and_then(Value::as_str)
+        .unwrap_or("production");
+    let overrides = target
+        .get("configurations")
+        .and_then(|c| c.get(configuration));
+    let get = |key: &str| {
+        overrides
+            .and_then(|o| o.get(key))
+            .or_else(|| options.and_then(|o| o.get(key)))
+    };
+
+    BuildOptions {
+        // Nx の project.json では executor と呼ぶ
+        builder: target
+            .get("builder")
+            .or_else(|| target.get("executor"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        configuration: if overrides.is_some() {
+            configuration.to_string()
+        } else {
+            String::new()
+        },
+        budgets: get("budgets")
+            .and_then(|b| serde_json::from_value(b.clone()).ok())
+            .unwrap_or_default(),
+        optimization: get("optimization").map(|v| match v {
+            Value::Object(parts) => parts.values().any(|p| p != &Value::Bool(false)),
+            _ => v.as_bool().unwrap_or(false),
+        }),
+        output_hashing: get("outputHashing")
+            .and_then(Value::as_str)
+            .map(String::from),
+        service_worker: get("serviceWorker").map(|v| match v {
+            Value::String(_) => true,
+            _ => v.as_bool().unwrap_or(false),
+        }),
+    }
+}
+
+// .eslintrc.json の rules と overrides[].rules から depConstraints を読む
+// (eslint.config.js 形式の設定は JavaScript のため読めない)
+fn eslint_dep_constraints(index: &ProjectIndex) -> Vec<DepConstraint> {
+    let path = format!("{}/.eslintrc.json", index.root.trim_end_matches('/'));
+    let Some(value) = index
+        .get(&path)
+        .and_then(|f| f.content())
+        .and_then(jsonc::parse)
+    else {
+        return Vec::new();
+    };
+
+    let overrides = value.get("overrides").and_then(Value::as_array);
+    let rule_sets = std::iter::once(&value)
+        .chain(overrides.into_iter().flatten())
+        .filter_map(|v| v.get("rules"));
+    for rules in rule_sets {
+        for name in BOUNDARY_RULES {
+            // "rule": ["error", { "depConstraints": [...] }]
+            let constraints = rules
+                .get(name)
+                .and_then(|rule| rule.get(1))
+                .and_then(|options| options.get("depConstraints"));
+            if let Some(constraints) = constraints
+                && let Ok(constraints) = serde_json::from_value(constraints.clone())
+            {
+                return constraints;
+            }
+        }
+    }
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempProject;
+
+    const ANGULAR_JSON: &str = r#"{
+  // コメントも書ける
+  "projects": {
+    "shop": {
+      "projectType": "application",
+      "root": "",
+      "sourceRoot": "src",
+      "architect": {
+        "build": {
+          "builder": "@angular-devkit/build-angular:application",
+          "options": {
+            "outputHashing": "none",
+            "budgets": [{ "type": "initial", "maximumWarning": "1mb" }]
+          },
+          "configurations": {
+            "production": {
+              "optimization": { "scripts": true, "styles": false },
+              "outputHashing": "all",
+              "serviceWorker": "ngsw-config.json",
+              "budgets": [{ "type": "initial", "maximumWarning": "500kb", "maximumError": "1mb" }]
+            }
+          },
+          "defaultConfiguration": "production"
+        }
+      }
+    },
+    "ui": {
+      "projectType": "library",
+      "root": "projects/ui",
+      "sourceRoot": "projects/ui/src"
+    }
+  }
+}"#;
+
+    #[test]
+    fn angular_json_projects() {
+        let project = TempProject::new(&[
+            ("angular.json", ANGULAR_JSON),
+            ("src/main.ts", ""),
+            ("projects/ui/src/button.ts", ""),
+        ]);
+        let workspace = discover(&project.index()).unwrap();
+        assert_eq!(workspace.kind, WorkspaceKind::AngularCli);
+        assert_eq!(workspace.file, project.path("angular.json"));
+
+        let names: Vec<&str> = workspace.projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["shop", "ui"]);
+
+        let shop = workspace.project("shop").unwrap();
+        assert_eq!(shop.project_type, "application");
+        assert_eq!(shop.analysis_root(), project.path("src"));
+        let ui = workspace.project("ui").unwrap();
+        assert_eq!(ui.project_type, "library");
+        assert_eq!(ui.root, project.path("projects/ui"));
+        assert!(ui.build.is_none());
+
+        // ライブラリの中のファイルはルートが深いライブラリのもの
+        let of = |path: &str| {
+            workspace
+                .project_of(&project.path(path))
+                .map(|i| &workspace.projects[i].name)
+        };
+        assert_eq!(of("src/main.ts").map(String::as_str), Some("shop"));
+        assert_eq!(
+            of("projects/ui/src/button.ts").map(String::as_str),
+            Some("ui")
+        );
+        assert_eq!(workspace.projects_in(&project.root).len(), 2);
+        let in_source_root: Vec<&str> = workspace
+            .projects_in(&project.path("src"))
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(in_source_root, ["shop"]);
+    }
+
+    #[test]
+    fn build_options_from_default_configuration() {
+        let project = TempProject::new(&[("angular.json", ANGULAR_JSON)]);
+        let workspace = discover(&project.index()).unwrap();
+        let build = workspace.project("shop").unwrap().build.as_ref().unwrap();
+
+        assert_eq!(build.builder, "@angular-devkit/build-angular:application");
+        assert_eq!(build.configuration, "production");
+        // configuration の値が options より優先される
+        assert_eq!(build.output_hashing.as_deref(), Some("all"));
+        assert_eq!(build.budgets.len(), 1);
+        assert_eq!(build.budgets[0].maximum_warning.as_deref(), Some("500kb"));
+        assert_eq!(build.budgets[0].maximum_error.as_deref(), Some("1mb"));
+        assert_eq!(build.optimization, Some(true));
+        assert_eq!(build.service_worker, Some(true));
+    }
+
+    #[test]
+    fn build_options_without_configuration() {
+        let target = serde_json::json!({
+            "executor": "@nx/angular:application",
+            "options": { "optimization": false, "serviceWorker": false }
+        });
+        let build = build_options(&target);
+        assert_eq!(build.builder, "@nx/angular:application");
+        assert_eq!(build.configuration, "");
+        assert_eq!(build.optimization, Some(false));
+        assert_eq!(build.service_worker, Some(false));
+        assert!(build.output_hashing.is_none());
+    }
+
+    #[test]
+    fn no_workspace_file() {
+        let project = TempProject::new(&[("src/main.ts", "")]);
+        assert!(discover(&project.index()).is_none());
+    }
+}